@@ -1,21 +1,23 @@
 use std::{
-    fs::File,
-    io::{prelude::*, BufReader},
+    io::{self, IsTerminal, Write},
     path::Path,
     process,
+    time::{Duration, Instant},
 };
 
 use clap::Parser;
 
-mod wordle;
-
+use wordle_solver::wordle;
+use wordle::Config;
 use wordle::Guess;
+use wordle::GuessStrategy;
+use wordle::RandomStrategy;
 use wordle::Wordle;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 struct Args {
-    /// The relative path to the dictionary/wordlist
+    /// The relative path to the dictionary/wordlist, or - to read it from stdin
     wordlist: Option<String>,
 
     /// The starting word
@@ -23,26 +25,117 @@ struct Args {
 
     /// The solution
     target: Option<String>,
+
+    /// Grade the whitespace-separated guesses in FILE against TARGET instead of solving
+    #[clap(long, number_of_values = 2, value_names = &["FILE", "TARGET"])]
+    grade: Option<Vec<String>>,
+
+    /// Stop guessing and list the candidates once this many or fewer remain (0 to never stop).
+    /// On a terminal, the solve pauses there and Enter guesses on
+    #[clap(long, default_value = "0")]
+    reveal_threshold: usize,
+
+    /// When listing the candidates, show only the N most likely and count the rest (needs
+    /// --reveal-threshold)
+    #[clap(long, value_name = "N")]
+    max_list: Option<usize>,
+
+    /// Keep words containing apostrophes or hyphens, treating them as ordinary letters
+    #[clap(long)]
+    allow_punctuation: bool,
+
+    /// The letters words may use, e.g. for non-English variants (defaults to a-z)
+    #[clap(long)]
+    alphabet: Option<String>,
+
+    /// Sort the wordlist alphabetically instead of keeping file order
+    #[clap(long)]
+    sort_dictionary: bool,
+
+    /// Color the guessed letters instead of printing emoji squares (only on a terminal)
+    #[clap(long)]
+    color: bool,
+
+    /// Open with the best word for the wordlist; then only `wordlist` and `target` are given
+    #[clap(long)]
+    auto_open: bool,
+
+    /// Allow a first guess of a different length than the target; missing letters score gray
+    #[clap(long)]
+    lenient: bool,
+
+    /// A file of words, one per line, never to guess unless nothing else is left
+    #[clap(long, value_name = "FILE")]
+    blocklist: Option<String>,
+
+    /// Start from the greens, yellows and grays in FILE (`green a 0`, `yellow t 2`, `gray s`)
+    #[clap(long, value_name = "FILE")]
+    constraints: Option<String>,
+
+    /// Also print how likely each guess is to be the answer
+    #[clap(long)]
+    verbose: bool,
 }
 
 fn main() {
     let args = Args::parse();
 
+    if let Some(grade) = &args.grade {
+        let guesses = match wordle::grade_from_file(Path::new(&grade[0]), &grade[1]) {
+            Err(why) => {
+                println!("{}", why);
+                process::exit(1);
+            },
+            Ok(value) => value
+        };
+
+        for guess in &guesses {
+            println!("{} {}", guess.get_formatted_result(), guess.guess);
+        }
+        return;
+    }
+
     let wordlist_path = args.wordlist.unwrap_or_else(|| {
         println!("Missing `wordlist` arg");
         process::exit(1);
     });
-    let first_guess = args.first_guess.unwrap_or_else(|| {
-        println!("Missing `first_guess` arg");
-        process::exit(1);
+    // With --auto-open there's no first guess to give, so the second positional is the target
+    let (first_guess, target) = match (args.auto_open, args.first_guess, args.target) {
+        (true, Some(target), None) => (None, Some(target)),
+        (_, first_guess, target) => (first_guess, target),
+    };
+    let first_guess = first_guess.unwrap_or_else(|| {
+        if !args.auto_open {
+            println!("Missing `first_guess` arg");
+            process::exit(1);
+        }
+        String::new()
     });
-    let target = args.target.unwrap_or_else(|| {
+    let target = target.unwrap_or_else(|| {
         println!("Missing `target` arg");
         process::exit(1);
     });
+    // Candidates are only ever listed once the reveal threshold is reached
+    if args.max_list.is_some() && args.reveal_threshold == 0 {
+        println!("`--max-list` needs `--reveal-threshold`");
+        process::exit(1);
+    }
+
+    let mut config = Config::new(wordlist_path, first_guess, target);
+    config.reveal_threshold = args.reveal_threshold;
+    config.max_list = args.max_list;
+    config.allow_punctuation = args.allow_punctuation;
+    config.sort_dictionary = args.sort_dictionary;
+    config.color = args.color;
+    config.verbose = args.verbose;
+    config.auto_open = args.auto_open;
+    config.lenient = args.lenient;
+    config.blocklist_file = args.blocklist;
+    if let Some(alphabet) = args.alphabet {
+        config.alphabet = alphabet.chars().collect();
+    }
 
-    let config = Config::new(wordlist_path, first_guess, target);
-    let lines = match read_lines_from_file(Path::new(&config.wordfile)) {
+    let word_list = match config.load_dictionary() {
         Err(why) => {
             println!("{}", why);
             process::exit(1);
@@ -50,19 +143,63 @@ fn main() {
         Ok(value) => value
     };
 
-    println!("Read {} words from {}", lines.len(), config.wordfile);
+    if word_list.blank_lines > 0 {
+        println!("Skipped {} blank line(s) in {}", word_list.blank_lines, config.wordfile_name());
+    }
+    if word_list.invalid_words > 0 {
+        let name = config.wordfile_name();
+        println!("Skipped {} invalid word(s) in {}", word_list.invalid_words, name);
+    }
+    let lines = word_list.words;
+
+    println!("Read {} words from {}", lines.len(), config.wordfile_name());
 
     let mut wordle = Wordle::new(lines);
+    wordle.allow_punctuation = config.allow_punctuation;
+    wordle.alphabet = config.alphabet.clone();
+    wordle.blocklist = match config.load_blocklist() {
+        Err(why) => {
+            println!("{}", why);
+            process::exit(1);
+        },
+        Ok(value) => value
+    };
 
+    if let Some(path) = &args.constraints {
+        let applied = wordle::load_constraints(Path::new(path))
+            .map_err(|why| why.to_string())
+            .and_then(|constraints| {
+                wordle.apply_constraints(&constraints).map_err(|why| why.to_string())
+            });
+        if let Err(why) = applied {
+            println!("{}", why);
+            process::exit(1);
+        }
+        println!("{} words match the constraints in {}", wordle.dictionary.len(), path);
+    }
+
+    let opener = config.opening_guess(&wordle.dictionary);
+    let init_result = if config.lenient {
+        wordle::check_guess_lenient(&opener, &config.target, wordle::LengthPolicy::Pad)
+    } else {
+        wordle::check_guess(&opener, &config.target)
+    };
     let init_guess = Guess {
-        guess: config.init_guess.clone(),
-        result: wordle::check_guess(&config.init_guess, &config.target),
+        result: init_result,
+        guess: opener,
     };
     println!("Initial guess: {}", &init_guess.guess);
-    println!("Result: {}", &init_guess.get_formatted_result());
+    println!("Result: {}", format_result(&init_guess, &config));
 
+    let opened_with = init_guess.guess.clone();
     let dict_size_before = wordle.dictionary.len();
-    wordle.add_guess(init_guess);
+    if let Err(why) = wordle.add_guess(init_guess) {
+        println!("{}", why);
+        process::exit(1);
+    }
+    if config.verbose && !wordle.is_solved() {
+        print_closeness(&wordle, &opened_with);
+    }
 
     let dict_size_after = wordle.dictionary.len();
     println!(
@@ -75,23 +212,61 @@ fn main() {
         &wordle.guesses.len()
     );
 
-    while &wordle.dictionary.len() > &0 {
-        let next_word = wordle::choose_next_guess(&wordle.dictionary);
+    let mut choice_time = Duration::ZERO;
+    let mut revealed = false;
+    while !wordle.dictionary.is_empty() {
+        if let Some(shortlist) = wordle.shortlist(config.reveal_threshold).filter(|_| !revealed) {
+            println!("Stopping with {} candidate(s) left:", shortlist.len());
+            match config.max_list {
+                Some(max_list) => {
+                    let ranked: Vec<String> =
+                        wordle.ranked_candidates().into_iter().map(|(word, _)| word).collect();
+                    println!("{}", wordle::format_candidates_capped(&ranked, max_list));
+                },
+                None => println!("{}", wordle::format_candidates(shortlist)),
+            }
+            // Run interactively, wait on the list; piped or scripted runs just stop here
+            if !io::stdin().is_terminal() || !keep_guessing() {
+                break;
+            }
+            revealed = true;
+        }
+
+        let started = Instant::now();
+        let next_word = match RandomStrategy.choose(&wordle) {
+            Some(word) => word,
+            None => break,
+        };
+        let elapsed = started.elapsed();
+        choice_time += elapsed;
         let next_guess = Guess {
-            guess: next_word.clone(),
             result: wordle::check_guess(&next_word, &config.target),
+            guess: next_word,
         };
 
         println!("Next guess: {}", &next_guess.guess);
-        println!("Result: {}", &next_guess.get_formatted_result());
+        if config.verbose {
+            let chance = wordle.guess_win_probability(&next_guess.guess);
+            println!("Guessing {:?} - {:.0}% chance to win", &next_guess.guess, chance * 100.0);
+            println!("Chose it in {:?}", elapsed);
+        }
+        println!("Result: {}", format_result(&next_guess, &config));
 
+        let guessed = next_guess.guess.clone();
         let dict_size_before = wordle.dictionary.len();
-        wordle.add_guess(next_guess);
+        if let Err(why) = wordle.add_guess(next_guess) {
+            println!("{}", why);
+            process::exit(1);
+        }
 
         if wordle.is_solved() {
             break
         }
 
+        if config.verbose {
+            print_closeness(&wordle, &guessed);
+        }
+
         let dict_size_after = wordle.dictionary.len();
         println!(
             "Removed {} words from dict after guess: {}",
@@ -110,32 +285,32 @@ fn main() {
         println!("The correct word is {:?}.", &wordle.guesses.last().unwrap().guess);
         println!("It took {:?} guesses to find it.", &wordle.guesses.len());
     }
+    if config.verbose {
+        println!("Spent {:?} choosing guesses in total", choice_time);
+    }
 }
 
-struct Config {
-    wordfile: String,
-    init_guess: String,
-    target: String,
-}
+// Pauses until the user presses Enter to guess on, or types q (or closes stdin) to stop
+fn keep_guessing() -> bool {
+    print!("Press Enter to keep guessing, or q to stop: ");
+    let _ = io::stdout().flush();
 
-impl Config {
-    fn new(wordfile: String, init_guess: String, target: String) -> Config {
-        Config {
-            wordfile,
-            init_guess,
-            target,
-        }
+    let mut line = String::new();
+    match io::stdin().read_line(&mut line) {
+        Ok(0) | Err(_) => false,
+        Ok(_) => line.trim() != "q",
     }
 }
 
-fn read_lines_from_file(filename: &Path) -> Result<Vec<String>, String> {
-    let file = File::open(&filename);
-    if !file.is_ok() {
-        return Err(format!("Could not open file {:?}", &filename));
-    }
+// A warmer/colder hint after a miss, once the guess's own feedback is known
+fn print_closeness(wordle: &Wordle, guess: &str) {
+    println!("{:?} shares {} letter(s) with the answer", guess, wordle.closeness(guess));
+}
 
-    let buf = BufReader::new(file.unwrap());
-    Ok(buf.lines()
-        .map(|l| l.expect("Could not parse line"))
-        .collect())
+fn format_result(guess: &Guess, config: &Config) -> String {
+    if config.color && io::stdout().is_terminal() {
+        guess.render_colored()
+    } else {
+        guess.get_formatted_result()
+    }
 }
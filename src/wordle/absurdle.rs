@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use super::{check_guess, to_gyx, Correctness};
+
+/// An adversarial host that never commits to an answer: each response is the feedback
+/// shared by the most remaining candidates, and only those candidates are kept.
+#[derive(Debug, Clone)]
+pub struct AbsurdleHost {
+  pub candidates: Vec<String>,
+}
+
+impl AbsurdleHost {
+  pub fn new(candidates: Vec<String>) -> AbsurdleHost {
+    AbsurdleHost { candidates }
+  }
+
+  pub fn respond(&mut self, guess: &str) -> Vec<Correctness> {
+    let mut groups: HashMap<Vec<Correctness>, Vec<String>> = HashMap::new();
+    for candidate in self.candidates.drain(..) {
+      groups
+        .entry(check_guess(guess, &candidate))
+        .or_default()
+        .push(candidate);
+    }
+
+    // Equal-sized groups are settled by their GYX code so responses are deterministic
+    let largest = groups
+      .into_iter()
+      .max_by(|a, b| {
+        a.1
+          .len()
+          .cmp(&b.1.len())
+          .then_with(|| to_gyx(&b.0).cmp(&to_gyx(&a.0)))
+      });
+
+    match largest {
+      Some((pattern, group)) => {
+        self.candidates = group;
+        pattern
+      }
+      None => vec![Correctness::Incorrect; guess.chars().count()],
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::partition_by_guess;
+
+  fn candidates() -> Vec<String> {
+    words(&["baker", "caker", "daker", "salty", "shirt", "skirt", "lynch"])
+  }
+
+  #[test]
+  fn it_should_respond_with_the_pattern_leaving_the_most_candidates() {
+    let mut host = AbsurdleHost::new(candidates());
+
+    for guess in ["baker", "shirt"] {
+      let largest = partition_by_guess(guess, &host.candidates)
+        .values()
+        .copied()
+        .max()
+        .unwrap();
+
+      let pattern = host.respond(guess);
+
+      assert_eq!(host.candidates.len(), largest);
+      assert!(host.candidates.iter().all(|c| check_guess(guess, c) == pattern));
+    }
+  }
+
+  #[test]
+  fn it_should_respond_all_gray_once_no_candidates_remain() {
+    let mut host = AbsurdleHost::new(vec![]);
+
+    assert_eq!(host.respond("salty"), vec![Correctness::Incorrect; 5]);
+  }
+}
@@ -0,0 +1,1222 @@
+use std::collections::{HashMap, HashSet};
+
+use super::simulate::BudgetTracker;
+use super::{
+  best_discriminator, best_guess, check_guess, entropy, estimate_difficulty, partition_by_guess,
+  to_gyx, ComputeBudget, ConstraintKind, Correctness, Guess, GuessError, Reason, Strategy, Wordle,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressReport {
+  pub remaining: usize,
+  /// log2 of the remaining candidate count
+  pub bits_to_go: f64,
+  pub top_suggestion: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GuessGrade {
+  S,
+  A,
+  B,
+  C,
+}
+
+// A candidate set is stuck when it's at least this big but the words differ in no more
+// than STUCK_POSITIONS places, so guessing candidates one by one could take a guess each
+const STUCK_CANDIDATES: usize = 3;
+const STUCK_POSITIONS: usize = 2;
+
+// A guess that couldn't have been the answer is wasted if it cut the field by less than
+// this many bits, i.e. didn't even halve it
+const WASTED_BITS: f64 = 1.0;
+
+// Beyond this many candidates an exact worst-case search is too slow to run on demand
+const MINIMAX_MAX_CANDIDATES: usize = 12;
+
+// Fractions of the best available entropy a guess needs for each grade below S
+const GRADE_A_RATIO: f64 = 0.9;
+const GRADE_B_RATIO: f64 = 0.7;
+
+/// How much one past guess narrowed the field, next to the best guess available then.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepAnalysis {
+  pub guess: String,
+  pub candidates_before: usize,
+  pub candidates_after: usize,
+  /// log2 of how many times smaller the field got
+  pub actual_bits: f64,
+  /// Expected information of `best_guess`, the entropy solver's pick at this step
+  pub optimal_bits: f64,
+  pub best_guess: Option<String>,
+}
+
+/// What a frontend needs after each turn, as returned by `Wordle::step`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepResult {
+  /// The suggestion the feedback was applied to
+  pub guess: String,
+  pub next_suggestion: Option<String>,
+  pub remaining: usize,
+  /// log2 of how many times smaller the field got
+  pub bits_gained: f64,
+  pub solved: bool,
+}
+
+/// How much `Wordle::hint` gives away, from least to most.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HintLevel {
+  /// A letter the answer contains
+  Letter,
+  /// A letter and where it goes
+  Position,
+  /// The word the solver would guess next
+  Suggestion,
+}
+
+const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
+
+/// Whether `c` is a vowel, counting 'y' as one only if `treat_y_as_vowel` is set.
+pub fn is_vowel(c: char, treat_y_as_vowel: bool) -> bool {
+  let c = c.to_ascii_lowercase();
+  VOWELS.contains(&c) || (treat_y_as_vowel && c == 'y')
+}
+
+// Minimax over guessing each candidate in turn. Candidate sets recur along different
+// lines of play, so results are memoised by the sorted set. Each guess tried is a node of
+// `tracker`'s budget; once it runs out, a set is costed at a guess per candidate, which
+// guessing them one at a time always achieves.
+fn worst_case_guesses(
+  candidates: &[String],
+  memo: &mut HashMap<Vec<String>, usize>,
+  tracker: &mut BudgetTracker,
+) -> usize {
+  if candidates.len() <= 1 {
+    return candidates.len();
+  }
+
+  let mut key = candidates.to_vec();
+  key.sort();
+  if let Some(&guesses) = memo.get(&key) {
+    return guesses;
+  }
+
+  let mut best = candidates.len();
+  for guess in candidates {
+    if !tracker.take_node() {
+      break;
+    }
+
+    let mut groups: HashMap<Vec<Correctness>, Vec<String>> = HashMap::new();
+    for word in candidates.iter().filter(|w| *w != guess) {
+      groups.entry(check_guess(guess, word)).or_default().push(word.clone());
+    }
+
+    let worst = groups
+      .values()
+      .map(|group| 1 + worst_case_guesses(group, memo, tracker))
+      .max()
+      .unwrap_or(1);
+    best = best.min(worst);
+  }
+
+  memo.insert(key, best);
+  best
+}
+
+// log2 of a candidate count, with no candidates counting as no uncertainty
+fn bits(count: usize) -> f64 {
+  if count == 0 {
+    0.0
+  } else {
+    (count as f64).log2()
+  }
+}
+
+/// The distinct letters of `word`, ignoring anything outside `alphabet`.
+pub fn letter_coverage(word: &str, alphabet: &[char]) -> HashSet<char> {
+  word.chars().filter(|c| alphabet.contains(c)).collect()
+}
+
+/// How many of `words` each letter appears in, most widespread first.
+pub fn coverage_report(words: &[String], alphabet: &[char]) -> Vec<(char, usize)> {
+  let mut counts: HashMap<char, usize> = HashMap::new();
+
+  for word in words {
+    for c in letter_coverage(word, alphabet) {
+      *counts.entry(c).or_insert(0) += 1;
+    }
+  }
+
+  let mut report: Vec<(char, usize)> = counts.into_iter().collect();
+  report.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+  report
+}
+
+impl Wordle {
+  pub fn progress_report(&self) -> ProgressReport {
+    let remaining = self.dictionary.len();
+
+    ProgressReport {
+      remaining,
+      bits_to_go: bits(remaining),
+      top_suggestion: self.best_guess(Strategy::Entropy).cloned(),
+    }
+  }
+
+  /// The chance `guess` is the answer, treating every remaining candidate as equally
+  /// likely. Zero for a word that's already been ruled out.
+  pub fn guess_win_probability(&self, guess: &str) -> f64 {
+    if !self.dictionary.iter().any(|w| w == guess) {
+      return 0.0;
+    }
+
+    1.0 / self.dictionary.len() as f64
+  }
+
+  /// Letters known to be in the answer from greens and yellows, each repeated as many
+  /// times as the answer is known to contain it, in alphabetical order.
+  pub fn present_letters(&self) -> Vec<char> {
+    self
+      .constraints
+      .min_counts
+      .iter()
+      .flat_map(|(c, min)| std::iter::repeat_n(*c, *min))
+      .collect()
+  }
+
+  /// How many letters of `guess` are known to be in the answer, counting a repeated letter
+  /// only as often as the answer is known to have it. A warmer/colder hint: an answer
+  /// always scores `present_letters().len()`.
+  pub fn closeness(&self, guess: &str) -> usize {
+    self
+      .constraints
+      .min_counts
+      .iter()
+      .map(|(c, min)| guess.chars().filter(|l| l == c).count().min(*min))
+      .sum()
+  }
+
+  /// Every letter guessed so far, whatever its feedback, as it would show on the keyboard.
+  pub fn tested_letters(&self) -> HashSet<char> {
+    self.guesses.iter().flat_map(|g| g.guess.chars()).collect()
+  }
+
+  /// The most common vowel/consonant shape of the candidates, e.g. "CVCCV", decided by
+  /// majority at each position. Ties count as consonants, and so does 'y' unless
+  /// `treat_y_as_vowel` is set.
+  pub fn dominant_skeleton(&self) -> String {
+    let words: Vec<Vec<char>> = self.dictionary.iter().map(|w| w.chars().collect()).collect();
+    let len = words.iter().map(|w| w.len()).max().unwrap_or(0);
+
+    (0..len)
+      .map(|i| {
+        let (vowels, consonants) = words
+          .iter()
+          .filter_map(|w| w.get(i))
+          .fold((0, 0), |(v, c), letter| {
+            if is_vowel(*letter, self.treat_y_as_vowel) {
+              (v + 1, c)
+            } else {
+              (v, c + 1)
+            }
+          });
+
+        if vowels > consonants {
+          'V'
+        } else {
+          'C'
+        }
+      })
+      .collect()
+  }
+
+  /// Candidates spelled with exactly the letters in `present_letters`, in any order. Only
+  /// non-empty once every letter of the answer has been found.
+  pub fn anagram_candidates(&self) -> Vec<String> {
+    let mut known = self.present_letters();
+    known.sort_unstable();
+
+    self
+      .dictionary
+      .iter()
+      .filter(|word| {
+        let mut letters: Vec<char> = word.chars().collect();
+        letters.sort_unstable();
+        letters == known
+      })
+      .cloned()
+      .collect()
+  }
+
+  /// Every constraint gathered so far, one category per line: greens by position, yellows
+  /// with the positions they're excluded from, grays, then count bounds. Meant for
+  /// working out why a word was filtered, not for display to players.
+  pub fn constraint_debug(&self) -> String {
+    let c = &self.constraints;
+    let list = |items: Vec<String>| {
+      if items.is_empty() {
+        String::from("none")
+      } else {
+        items.join(", ")
+      }
+    };
+
+    let mut greens = c.correct_letters.clone();
+    greens.sort_by_key(|(_, i)| *i);
+    greens.dedup();
+    let greens = greens.iter().map(|(l, i)| format!("{:?} at {}", l, i)).collect();
+
+    let mut yellows = c.misplaced_letters.clone();
+    yellows.sort_unstable();
+    yellows.dedup();
+    let yellows = yellows
+      .iter()
+      .map(|l| {
+        let mut excluded: Vec<u32> =
+          c.excluded_positions.iter().filter(|(e, _)| e == l).map(|(_, i)| *i).collect();
+        excluded.sort_unstable();
+        excluded.dedup();
+        let excluded: Vec<String> = excluded.iter().map(|i| i.to_string()).collect();
+        format!("{:?} not at {}", l, excluded.join("/"))
+      })
+      .collect();
+
+    let mut grays = c.incorrect_letters.clone();
+    grays.sort_unstable();
+    grays.dedup();
+    let grays = grays.iter().map(|l| format!("{:?}", l)).collect();
+
+    let mut letters: Vec<char> = c.min_counts.keys().chain(c.max_counts.keys()).copied().collect();
+    letters.sort_unstable();
+    letters.dedup();
+    let counts = letters
+      .iter()
+      .map(|l| {
+        let min = c.min_counts.get(l).copied().unwrap_or(0);
+        match c.max_counts.get(l) {
+          Some(max) => format!("{:?} {}..={}", l, min, max),
+          None => format!("{:?} {}..", l, min),
+        }
+      })
+      .collect();
+
+    format!(
+      "greens: {}\nyellows: {}\ngrays: {}\ncounts: {}",
+      list(greens),
+      list(yellows),
+      list(grays),
+      list(counts)
+    )
+  }
+
+  /// How many words of the full dictionary each kind of constraint has ruled out this
+  /// session. Each word counts once, toward the first constraint it fails, in the order
+  /// `elimination_reason` checks them.
+  pub fn elimination_breakdown(&self) -> HashMap<ConstraintKind, usize> {
+    let remaining: HashSet<&str> = self.dictionary.iter().map(|w| w.as_str()).collect();
+    let guessed: HashSet<&str> = self.guesses.iter().map(|g| g.guess.as_str()).collect();
+    let mut breakdown = HashMap::new();
+
+    for word in self.full_dictionary.iter() {
+      if remaining.contains(word.as_str()) {
+        continue;
+      }
+
+      let reason = match self.constraints.violation(word) {
+        Some(reason) => reason,
+        None if guessed.contains(word.as_str()) => Reason::AlreadyGuessed,
+        None => Reason::NotInDictionary,
+      };
+      *breakdown.entry(reason.kind()).or_insert(0) += 1;
+    }
+
+    breakdown
+  }
+
+  /// The candidates with how likely each is to be the answer, most likely first, going by
+  /// `word_frequencies`. Words without a frequency count as zero, and ties are
+  /// alphabetical. With no frequencies loaded every candidate is equally likely and the
+  /// list is alphabetical.
+  pub fn ranked_candidates(&self) -> Vec<(String, f64)> {
+    let weight = |word: &str| -> f64 {
+      if self.word_frequencies.is_empty() {
+        1.0
+      } else {
+        self.word_frequencies.get(word).copied().unwrap_or(0.0)
+      }
+    };
+
+    let total: f64 = self.dictionary.iter().map(|w| weight(w)).sum();
+    let mut ranked: Vec<(String, f64)> = self
+      .dictionary
+      .iter()
+      .map(|w| {
+        let likelihood = if total > 0.0 { weight(w) / total } else { 0.0 };
+        (w.clone(), likelihood)
+      })
+      .collect();
+
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked
+  }
+
+  /// `strategy`'s next guess, remembered as the word the next `step`'s feedback is for.
+  pub fn suggest(&mut self, strategy: Strategy) -> Option<String> {
+    self.last_suggestion = self.best_guess(strategy).cloned();
+    self.last_suggestion.clone()
+  }
+
+  /// The word the next `step`'s feedback will be applied to, if one's been suggested.
+  pub fn last_suggestion(&self) -> Option<&str> {
+    self.last_suggestion.as_deref()
+  }
+
+  /// Plays one turn: applies `feedback` to the last suggestion, from `suggest` or the
+  /// previous step, then suggests the next guess from the narrowed candidates. With no
+  /// suggestion made yet, `strategy`'s choice from the current state is used.
+  pub fn step(
+    &mut self,
+    feedback: Vec<Correctness>,
+    strategy: Strategy,
+  ) -> Result<StepResult, GuessError> {
+    let guess = match self.last_suggestion.clone() {
+      Some(guess) => guess,
+      None => self.suggest(strategy).ok_or(GuessError::NothingToGuess)?,
+    };
+    if feedback.len() != guess.chars().count() {
+      return Err(GuessError::InvalidFeedback(to_gyx(&feedback)));
+    }
+
+    let before = self.dictionary.len();
+    self.add_guess(Guess {
+      guess: guess.clone(),
+      result: feedback,
+    })?;
+
+    let solved = self.is_solved();
+    self.last_suggestion = if solved {
+      None
+    } else {
+      self.best_guess(strategy).cloned()
+    };
+
+    Ok(StepResult {
+      guess,
+      next_suggestion: self.last_suggestion.clone(),
+      remaining: self.dictionary.len(),
+      bits_gained: bits(before) - bits(self.dictionary.len()),
+      solved,
+    })
+  }
+
+  /// How constrained the puzzle is, from 0.0 when nothing is known to 1.0 when every
+  /// position is green. It's the fraction of the alphabet ruled out at each position,
+  /// averaged over positions, where a letter is ruled out if it's gray, was yellow there,
+  /// has all its allowed copies already green elsewhere, or another letter is green there:
+  ///
+  /// `tightness = mean over positions i of (|alphabet| - possible(i)) / (|alphabet| - 1)`
+  pub fn tightness(&self) -> f64 {
+    let c = &self.constraints;
+    let len = match self.guesses.first() {
+      Some(guess) => guess.result.len(),
+      None => self.full_dictionary.iter().map(|w| w.chars().count()).max().unwrap_or(0),
+    };
+    if len == 0 || self.alphabet.len() < 2 {
+      return 0.0;
+    }
+
+    let green_at = |i: usize| c.correct_letters.iter().find(|(_, j)| *j as usize == i);
+    let ruled_out = |letter: char, i: usize| {
+      let greens = c.correct_letters.iter().filter(|(l, _)| *l == letter).count();
+      c.incorrect_letters.contains(&letter)
+        || c.excluded_positions.contains(&(letter, i as u32))
+        || c.max_counts.get(&letter).is_some_and(|max| *max <= greens)
+    };
+
+    let alphabet = self.alphabet.len() as f64;
+    let total: f64 = (0..len)
+      .map(|i| {
+        let possible = match green_at(i) {
+          Some(_) => 1,
+          None => self.alphabet.iter().filter(|l| !ruled_out(**l, i)).count().max(1),
+        };
+        (alphabet - possible as f64) / (alphabet - 1.0)
+      })
+      .sum();
+
+    total / len as f64
+  }
+
+  /// The uncertainty left, as log2 of the remaining candidate count.
+  pub fn state_entropy(&self) -> f64 {
+    bits(self.dictionary.len())
+  }
+
+  /// How much of the starting uncertainty the guesses so far have removed.
+  pub fn bits_resolved(&self) -> f64 {
+    bits(self.full_dictionary.len()) - self.state_entropy()
+  }
+
+  /// A floor on the guesses still needed in the worst case: one guess gives one of at most
+  /// 3^len feedback patterns, so k guesses can tell apart no more than (3^len)^k
+  /// candidates. At least one guess is needed while any candidate is left.
+  pub fn min_guesses_lower_bound(&self) -> usize {
+    if self.dictionary.is_empty() {
+      return 0;
+    }
+
+    let len = self.dictionary.iter().map(|w| w.chars().count()).max().unwrap_or(0);
+    // Kept above 1 so the loop below ends even if only empty words are left
+    let patterns = 3usize.saturating_pow(len as u32).max(2);
+
+    let mut guesses = 1;
+    let mut distinguishable = patterns;
+    while distinguishable < self.dictionary.len() {
+      guesses += 1;
+      distinguishable = distinguishable.saturating_mul(patterns);
+    }
+
+    guesses
+  }
+
+  /// The guesses still needed in the worst case, counting the one that finds the answer,
+  /// when each guess is the candidate that minimises it. `None` when there are too many
+  /// candidates to search exhaustively.
+  pub fn guesses_to_guaranteed_solve(&self) -> Option<usize> {
+    if self.dictionary.len() > MINIMAX_MAX_CANDIDATES {
+      return None;
+    }
+
+    Some(self.guesses_to_guaranteed_solve_within(ComputeBudget::unlimited()).0)
+  }
+
+  /// Like `guesses_to_guaranteed_solve`, but with no limit on the candidates, stopping once
+  /// `budget` runs out instead, with each guess tried counting as one node. A search cut
+  /// short still gives a worst case some strategy achieves, just not necessarily the
+  /// smallest; the flag says whether the search finished.
+  pub fn guesses_to_guaranteed_solve_within(&self, budget: ComputeBudget) -> (usize, bool) {
+    let mut tracker = BudgetTracker::new(budget);
+    let guesses = worst_case_guesses(&self.dictionary, &mut HashMap::new(), &mut tracker);
+
+    (guesses, tracker.exhaustive())
+  }
+
+  /// How many different feedback patterns `guess` could get across the candidates.
+  pub fn distinct_patterns(&self, guess: &str) -> usize {
+    partition_by_guess(guess, &self.dictionary).len()
+  }
+
+  /// Every feedback `guess` could get across the candidates, the patterns covering the
+  /// most candidates first. Ties are broken by the patterns' GYX spelling.
+  pub fn possible_feedbacks(&self, guess: &str) -> Vec<Vec<Correctness>> {
+    let mut groups: Vec<_> = partition_by_guess(guess, &self.dictionary)
+      .into_iter()
+      .collect();
+    groups.sort_by_cached_key(|(pattern, size)| (std::cmp::Reverse(*size), to_gyx(pattern)));
+
+    groups.into_iter().map(|(pattern, _)| pattern).collect()
+  }
+
+  /// Whether `guess` is guaranteed to leave enough of `remaining_budget` (counting this
+  /// guess) to find the answer. Any group of candidates can be cleared by guessing its
+  /// words one at a time, so the guess is safe when its largest unsolved feedback group
+  /// is no bigger than the guesses left after it.
+  pub fn is_safe_guess(&self, guess: &str, remaining_budget: usize) -> bool {
+    if remaining_budget == 0 {
+      return false;
+    }
+
+    let worst = partition_by_guess(guess, &self.dictionary)
+      .into_iter()
+      .filter(|(pattern, _)| !pattern.iter().all(|r| *r == Correctness::Correct))
+      .map(|(_, size)| size)
+      .max()
+      .unwrap_or(0);
+
+    worst < remaining_budget
+  }
+
+  /// Grades `guess` against the entropy solver's top suggestion: S for matching it, then
+  /// A, B or C as the guess falls further short of its information.
+  pub fn grade_guess(&self, guess: &str) -> GuessGrade {
+    let top = match self.best_guess(Strategy::Entropy) {
+      Some(top) => top,
+      None => return GuessGrade::S,
+    };
+
+    let best = entropy(top, &self.dictionary);
+    let info = entropy(guess, &self.dictionary);
+
+    if guess == top || best - info < 1e-9 {
+      GuessGrade::S
+    } else if info >= best * GRADE_A_RATIO {
+      GuessGrade::A
+    } else if info >= best * GRADE_B_RATIO {
+      GuessGrade::B
+    } else {
+      GuessGrade::C
+    }
+  }
+
+  /// Positions ranked by the entropy of their letter distribution over the candidates,
+  /// most uncertain first.
+  pub fn most_uncertain_positions(&self) -> Vec<(usize, f64)> {
+    let len = self.dictionary.iter().map(|w| w.chars().count()).max().unwrap_or(0);
+
+    let mut positions: Vec<(usize, f64)> = (0..len)
+      .map(|i| {
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for c in self.dictionary.iter().filter_map(|w| w.chars().nth(i)) {
+          *counts.entry(c).or_insert(0) += 1;
+        }
+
+        let total: usize = counts.values().sum();
+        let entropy = counts
+          .values()
+          .map(|&n| {
+            let p = n as f64 / total as f64;
+            -p * p.log2()
+          })
+          .sum();
+
+        (i, entropy)
+      })
+      .collect();
+
+    positions.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    positions
+  }
+
+  /// Replays the guesses against `answers`, scoring each by how far it actually cut the
+  /// field and by what the best guess from the full dictionary would have expected.
+  pub fn retrospective_analysis(&self, answers: &[String]) -> Vec<StepAnalysis> {
+    let mut remaining = answers.to_vec();
+    let mut steps = Vec::new();
+
+    for guess in &self.guesses {
+      let best = best_guess(&remaining, &self.full_dictionary, Strategy::Entropy).cloned();
+      let optimal_bits = best.as_ref().map_or(0.0, |b| entropy(b, &remaining));
+
+      let before = remaining.len();
+      remaining.retain(|answer| check_guess(&guess.guess, answer) == guess.result);
+      let after = remaining.len();
+
+      steps.push(StepAnalysis {
+        guess: guess.guess.clone(),
+        candidates_before: before,
+        candidates_after: after,
+        actual_bits: if after == 0 { 0.0 } else { (before as f64 / after as f64).log2() },
+        optimal_bits,
+        best_guess: best,
+      });
+    }
+
+    steps
+  }
+
+  /// Indices of the guesses that were wasted: they'd already been ruled out as the answer
+  /// and didn't even halve the candidates left at that point.
+  pub fn wasted_guesses(&self) -> Vec<usize> {
+    let applied = &self.applied_constraints;
+    let mut remaining: Vec<String> =
+      self.full_dictionary.iter().filter(|w| applied.allows(w)).cloned().collect();
+    let mut wasted = Vec::new();
+
+    for (i, guess) in self.guesses.iter().enumerate() {
+      let possible = remaining.contains(&guess.guess);
+
+      let before = remaining.len();
+      remaining.retain(|w| *w != guess.guess && check_guess(&guess.guess, w) == guess.result);
+
+      if !possible && bits(before) - bits(remaining.len()) < WASTED_BITS {
+        wasted.push(i);
+      }
+    }
+
+    wasted
+  }
+
+  /// A word from `allowed`, not necessarily a candidate, that best tells the candidates
+  /// apart, if they're stuck: several words that differ in only one or two positions, like
+  /// "batch", "catch", "hatch" and "latch". `None` otherwise.
+  pub fn recommend_probe(&self, allowed: &[String]) -> Option<String> {
+    if self.dictionary.len() < STUCK_CANDIDATES {
+      return None;
+    }
+
+    let words: Vec<Vec<char>> = self.dictionary.iter().map(|w| w.chars().collect()).collect();
+    let len = words.iter().map(|w| w.len()).max().unwrap_or(0);
+    let differing = (0..len)
+      .filter(|i| words.iter().any(|w| w.get(*i) != words[0].get(*i)))
+      .count();
+
+    if differing > STUCK_POSITIONS {
+      return None;
+    }
+
+    best_discriminator(&self.dictionary, allowed).cloned()
+  }
+
+  /// Groups the candidates by where they put the letters known to be in the answer, e.g.
+  /// "_a__e" for every word with the known 'a' and 'e' in those spots. Greens land in the
+  /// same place for everyone, so groups differ in where they put the yellows.
+  pub fn group_candidates_by_template(&self) -> HashMap<String, Vec<String>> {
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+
+    for word in &self.dictionary {
+      let template: String = word
+        .chars()
+        .map(|c| if self.constraints.min_counts.contains_key(&c) { c } else { '_' })
+        .collect();
+      groups.entry(template).or_default().push(word.clone());
+    }
+
+    groups
+  }
+
+  /// The `n` current candidates that would take the entropy solver the most guesses,
+  /// hardest first. One the solver never finds ranks above them all, at one guess more
+  /// than trying every candidate in turn.
+  pub fn hardest_consistent_answers(&self, n: usize) -> Vec<(String, usize)> {
+    let unsolved = self.dictionary.len() + 1;
+    let mut ranked: Vec<(String, usize)> = self
+      .dictionary
+      .iter()
+      .map(|answer| {
+        let difficulty = estimate_difficulty(answer, &self.dictionary).unwrap_or(unsolved);
+        (answer.clone(), difficulty)
+      })
+      .collect();
+
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(n);
+    ranked
+  }
+
+  /// A nudge towards the answer, more specific at each `level`. Letters and positions
+  /// shared by every candidate are preferred over ones the guesses have already shown.
+  pub fn hint(&self, level: HintLevel) -> String {
+    if self.dictionary.is_empty() {
+      return String::from("No word fits the feedback so far");
+    }
+
+    match level {
+      HintLevel::Letter => {
+        let known: Vec<char> = self.constraints.min_counts.keys().copied().collect();
+        let shared = self.shared_letters();
+        match shared.iter().find(|c| !known.contains(c)).or(shared.first()) {
+          Some(c) => format!("The answer contains '{}'", c),
+          None => String::from("No letter is in every remaining word"),
+        }
+      }
+      HintLevel::Position => {
+        let shared: Vec<(usize, char)> = self.dictionary[0]
+          .chars()
+          .enumerate()
+          .filter(|(i, c)| self.dictionary.iter().all(|w| w.chars().nth(*i) == Some(*c)))
+          .collect();
+        let known = |(i, c): &(usize, char)| {
+          self.constraints.correct_letters.contains(&(*c, *i as u32))
+        };
+        match shared.iter().find(|p| !known(p)).or(shared.first()) {
+          Some((i, c)) => format!("Letter {} is '{}'", i + 1, c),
+          None => String::from("No position is settled yet"),
+        }
+      }
+      HintLevel::Suggestion => match self.best_guess(Strategy::Entropy) {
+        Some(word) => format!("Try \"{}\"", word),
+        None => String::from("There is nothing left to suggest"),
+      },
+    }
+  }
+
+  // Letters in every candidate, in alphabetical order
+  fn shared_letters(&self) -> Vec<char> {
+    let mut letters: Vec<char> = self.dictionary[0].chars().collect();
+    letters.sort_unstable();
+    letters.dedup();
+    letters.retain(|c| self.dictionary.iter().all(|w| w.contains(*c)));
+    letters
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::{english_alphabet, Guess};
+
+  #[test]
+  fn it_should_report_bits_to_go_as_log2_of_remaining() {
+    let wordle = Wordle::new(words(&[
+      "salty", "those", "shirt", "skirt", "lynch", "label", "baker", "faker",
+    ]));
+
+    let report = wordle.progress_report();
+
+    assert_eq!(report.remaining, 8);
+    assert!((report.bits_to_go - 3.0).abs() < 1e-9);
+    assert!(report.top_suggestion.is_some());
+  }
+
+  #[test]
+  fn it_should_rank_answers_by_descending_difficulty() {
+    let wordle =
+      Wordle::new(words(&["baker", "caker", "daker", "faker", "maker", "taker", "shirt"]));
+
+    let hardest = wordle.hardest_consistent_answers(4);
+
+    assert_eq!(hardest.len(), 4);
+    assert!(hardest.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    assert!(hardest[0].1 > 1);
+  }
+
+  #[test]
+  fn it_should_rank_an_answer_the_solver_never_finds_hardest() {
+    // The solver's own game allows no apostrophes, so it can never guess "don't"
+    let wordle = Wordle::new(words(&["baker", "caker", "daker", "don't"]));
+
+    let hardest = wordle.hardest_consistent_answers(2);
+
+    assert_eq!(hardest[0], (String::from("don't"), 5));
+    assert!(hardest[1].1 < 5);
+  }
+
+  #[test]
+  fn it_should_rank_a_varying_position_above_a_determined_one() {
+    let wordle = Wordle::new(words(&["batch", "catch", "hatch", "latch"]));
+
+    let positions = wordle.most_uncertain_positions();
+
+    assert_eq!(positions.len(), 5);
+    assert_eq!(positions[0].0, 0);
+    assert!((positions[0].1 - 2.0).abs() < 1e-9);
+    let second = positions.iter().find(|(i, _)| *i == 1).unwrap();
+    assert_eq!(second.1, 0.0);
+  }
+
+  #[test]
+  fn it_should_count_each_letter_once_per_word() {
+    let words = words(&["eerie", "shirt", "skirt"]);
+
+    let report = coverage_report(&words, &english_alphabet());
+
+    assert_eq!(&report[..3], &[('i', 3), ('r', 3), ('s', 2)]);
+    assert!(report.contains(&('e', 1)));
+    assert!(report.contains(&('h', 1)));
+    assert_eq!(report.len(), 7);
+  }
+
+  #[test]
+  fn it_should_count_more_patterns_for_a_more_discriminating_guess() {
+    let wordle = Wordle::new(words(&["baker", "caker", "daker", "faker"]));
+
+    assert_eq!(wordle.distinct_patterns("bcdfz"), 4);
+    assert_eq!(wordle.distinct_patterns("baker"), 2);
+  }
+
+  #[test]
+  fn it_should_list_one_feedback_per_partition_largest_first() {
+    let wordle = Wordle::new(words(&["baker", "caker", "daker", "faker", "hiker"]));
+
+    let feedbacks = wordle.possible_feedbacks("baker");
+    assert_eq!(feedbacks.len(), wordle.distinct_patterns("baker"));
+    assert_eq!(to_gyx(&feedbacks[0]), "XGGGG");
+    assert_eq!(to_gyx(&feedbacks[1]), "GGGGG");
+    assert_eq!(to_gyx(&feedbacks[2]), "XXGGG");
+  }
+
+  #[test]
+  fn it_should_only_cover_letters_in_the_alphabet() {
+    let alphabet = vec!['d', 'n', 'o', 't'];
+
+    let coverage = letter_coverage("don't", &alphabet);
+
+    assert_eq!(coverage, ['d', 'n', 'o', 't'].into_iter().collect());
+    assert!(letter_coverage("ehrs", &alphabet).is_empty());
+  }
+
+  #[test]
+  fn it_should_grade_the_optimal_guess_highest() {
+    let wordle = Wordle::new(words(&["baker", "caker", "daker", "faker", "bcdfz"]));
+    let top = wordle.best_guess(Strategy::Entropy).unwrap().clone();
+
+    assert_eq!(wordle.grade_guess(&top), GuessGrade::S);
+    assert_eq!(wordle.grade_guess("zzzzz"), GuessGrade::C);
+  }
+
+  #[test]
+  fn it_should_report_no_bits_to_go_when_nothing_remains() {
+    let report = Wordle::new(vec![]).progress_report();
+
+    assert_eq!(report.remaining, 0);
+    assert_eq!(report.bits_to_go, 0.0);
+    assert!(report.top_suggestion.is_none());
+  }
+
+  #[test]
+  fn it_should_give_each_of_three_candidates_a_third_chance_to_win() {
+    let wordle = Wordle::new(words(&["baker", "caker", "daker"]));
+
+    assert!((wordle.guess_win_probability("caker") - 1.0 / 3.0).abs() < 1e-9);
+    assert_eq!(wordle.guess_win_probability("shirt"), 0.0);
+  }
+
+  #[test]
+  fn it_should_compare_each_past_guess_with_the_best_one() {
+    let answers = words(&["baker", "caker", "daker", "faker"]);
+    let mut wordle = Wordle::new(answers.clone());
+    for word in ["baker", "faker"] {
+      wordle
+        .add_guess(Guess {
+          guess: String::from(word),
+          result: check_guess(word, "faker"),
+        })
+        .unwrap();
+    }
+
+    let steps = wordle.retrospective_analysis(&answers);
+
+    assert_eq!(steps.len(), 2);
+    assert_eq!((steps[0].candidates_before, steps[0].candidates_after), (4, 3));
+    assert!((steps[0].actual_bits - (4.0f64 / 3.0).log2()).abs() < 1e-9);
+    assert!(steps[0].optimal_bits >= steps[0].actual_bits);
+    assert_eq!((steps[1].candidates_before, steps[1].candidates_after), (3, 1));
+    assert!((steps[1].actual_bits - 3.0f64.log2()).abs() < 1e-9);
+  }
+
+  #[test]
+  fn it_should_recommend_a_probe_for_the_atch_family() {
+    let family = words(&["batch", "catch", "hatch", "latch", "match", "patch"]);
+    let mut allowed = family.clone();
+    allowed.push(String::from("chomp"));
+    allowed.push(String::from("salty"));
+
+    let probe = Wordle::new(family).recommend_probe(&allowed);
+
+    assert_eq!(probe.as_deref(), Some("chomp"));
+  }
+
+  #[test]
+  fn it_should_not_recommend_a_probe_when_candidates_vary_widely() {
+    let wordle = Wordle::new(words(&["salty", "those", "shirt", "lynch"]));
+
+    assert!(wordle.recommend_probe(&wordle.dictionary).is_none());
+  }
+
+  #[test]
+  fn it_should_group_candidates_by_where_known_letters_sit() {
+    let mut wordle = Wordle::new(words(&["baker", "bread", "brake", "lynch"]));
+    wordle
+      .add_guess(Guess {
+        guess: String::from("rhino"),
+        result: check_guess("rhino", "baker"),
+      })
+      .unwrap();
+
+    let groups = wordle.group_candidates_by_template();
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups["____r"], vec!["baker"]);
+    assert_eq!(groups["_r___"], vec!["bread", "brake"]);
+  }
+
+  #[test]
+  fn it_should_report_two_bits_resolved_going_from_sixteen_to_four() {
+    let words: Vec<String> = (b'a'..b'q').map(|c| format!("{}aker", c as char)).collect();
+    let mut wordle = Wordle::new(words);
+    assert!((wordle.state_entropy() - 4.0).abs() < 1e-9);
+    assert_eq!(wordle.bits_resolved(), 0.0);
+
+    wordle.dictionary.truncate(4);
+
+    assert!((wordle.state_entropy() - 2.0).abs() < 1e-9);
+    assert!((wordle.bits_resolved() - 2.0).abs() < 1e-9);
+  }
+
+  #[test]
+  fn it_should_repeat_a_letter_known_to_appear_twice() {
+    let mut wordle = Wordle::new(vec![String::from("geese"), String::from("sense")]);
+    wordle
+      .add_guess(Guess {
+        guess: String::from("speed"),
+        result: check_guess("speed", "geese"),
+      })
+      .unwrap();
+
+    assert_eq!(wordle.present_letters(), vec!['e', 'e', 's']);
+  }
+
+  #[test]
+  fn it_should_report_the_majority_skeleton() {
+    let wordle = Wordle::new(words(&["baker", "caker", "sonic", "adobe"]));
+
+    assert_eq!(wordle.dominant_skeleton(), "CVCVC");
+    assert_eq!(Wordle::new(vec![]).dominant_skeleton(), "");
+  }
+
+  #[test]
+  fn it_should_only_count_y_as_a_vowel_when_asked() {
+    let mut wordle = Wordle::new(vec![String::from("myths")]);
+
+    assert_eq!(wordle.dominant_skeleton(), "CCCCC");
+
+    wordle.treat_y_as_vowel = true;
+    assert_eq!(wordle.dominant_skeleton(), "CVCCC");
+    assert!(!is_vowel('y', false));
+  }
+
+  #[test]
+  fn it_should_only_call_a_probe_safe_with_a_guess_to_spare() {
+    let wordle = Wordle::new(words(&["baker", "caker", "daker", "faker"]));
+
+    assert!(!wordle.is_safe_guess("bcdfz", 1));
+    assert!(wordle.is_safe_guess("bcdfz", 2));
+    assert!(!wordle.is_safe_guess("baker", 2));
+    assert!(wordle.is_safe_guess("baker", 4));
+    assert!(!wordle.is_safe_guess("baker", 0));
+  }
+
+  #[test]
+  fn it_should_credit_a_green_with_the_words_it_ruled_out() {
+    let mut wordle = Wordle::new(words(&["shirt", "skirt", "salty", "lynch", "those"]));
+    wordle
+      .add_guess(Guess {
+        guess: String::from("szzzz"),
+        result: check_guess("szzzz", "shirt"),
+      })
+      .unwrap();
+
+    let breakdown = wordle.elimination_breakdown();
+
+    assert_eq!(breakdown.get(&ConstraintKind::Green), Some(&2));
+    assert_eq!(breakdown.values().sum::<usize>(), 2);
+
+    wordle
+      .add_guess(Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "shirt"),
+      })
+      .unwrap();
+    let breakdown = wordle.elimination_breakdown();
+    assert_eq!(breakdown.get(&ConstraintKind::Green), Some(&2));
+    assert_eq!(breakdown.get(&ConstraintKind::Gray), Some(&1));
+  }
+
+  #[test]
+  fn it_should_rank_the_most_frequent_candidate_first() {
+    let mut wordle = Wordle::new(words(&["skirt", "shirt", "those"]));
+
+    let alphabetical: Vec<String> =
+      wordle.ranked_candidates().into_iter().map(|(w, _)| w).collect();
+    assert_eq!(alphabetical, vec!["shirt", "skirt", "those"]);
+
+    wordle.word_frequencies.insert(String::from("those"), 30.0);
+    wordle.word_frequencies.insert(String::from("shirt"), 10.0);
+
+    let ranked = wordle.ranked_candidates();
+    assert_eq!(ranked[0], (String::from("those"), 0.75));
+    assert_eq!(ranked[1], (String::from("shirt"), 0.25));
+    assert_eq!(ranked[2], (String::from("skirt"), 0.0));
+  }
+
+  #[test]
+  fn it_should_give_a_more_specific_hint_at_each_level() {
+    let mut wordle = Wordle::new(words(&["baker", "caker", "daker", "faker", "hiker"]));
+    wordle
+      .add_guess(Guess {
+        guess: String::from("hiker"),
+        result: check_guess("hiker", "daker"),
+      })
+      .unwrap();
+
+    assert_eq!(wordle.hint(HintLevel::Letter), "The answer contains 'a'");
+    assert_eq!(wordle.hint(HintLevel::Position), "Letter 2 is 'a'");
+    let suggestion = wordle.hint(HintLevel::Suggestion);
+    assert!(["\"baker\"", "\"caker\"", "\"daker\"", "\"faker\""]
+      .iter()
+      .any(|word| suggestion == format!("Try {}", word)));
+  }
+
+  #[test]
+  fn it_should_report_everything_a_frontend_needs_after_each_step() {
+    let mut wordle = Wordle::new(words(&["baker", "caker", "daker", "faker"]));
+
+    let first = wordle.step(check_guess("baker", "caker"), Strategy::Entropy).unwrap();
+
+    assert_eq!(first.guess, "baker");
+    assert_eq!(first.remaining, 3);
+    assert!((first.bits_gained - (2.0 - 3f64.log2())).abs() < 1e-9);
+    assert!(!first.solved);
+    assert_eq!(first.next_suggestion.as_deref(), Some("caker"));
+    assert_eq!(
+      wordle.step(vec![Correctness::Correct], Strategy::Entropy),
+      Err(GuessError::InvalidFeedback(String::from("G")))
+    );
+
+    let second = wordle.step(check_guess("caker", "caker"), Strategy::Entropy).unwrap();
+
+    assert_eq!(second.guess, "caker");
+    assert!(second.solved);
+    assert_eq!(second.next_suggestion, None);
+  }
+
+  #[test]
+  fn it_should_apply_feedback_to_the_word_actually_suggested() {
+    let mut wordle = Wordle::new(words(&["baker", "caker", "daker", "faker"]));
+
+    assert_eq!(wordle.suggest(Strategy::Entropy).as_deref(), Some("baker"));
+    // A player's own guess in between changes what the strategy would pick now
+    wordle
+      .add_guess(Guess {
+        guess: String::from("faker"),
+        result: check_guess("faker", "daker"),
+      })
+      .unwrap();
+
+    let step = wordle.step(check_guess("baker", "daker"), Strategy::Entropy).unwrap();
+
+    assert_eq!(step.guess, "baker");
+    assert_eq!(wordle.last_suggestion(), step.next_suggestion.as_deref());
+    assert_eq!(wordle.guesses.last().unwrap().guess, "baker");
+    assert_eq!(step.remaining, 2);
+  }
+
+  #[test]
+  fn it_should_count_the_known_letters_a_guess_shares() {
+    let mut wordle = Wordle::new(vec![String::from("those")]);
+    wordle
+      .add_guess(Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "those"),
+      })
+      .unwrap();
+
+    assert_eq!(wordle.closeness("shirt"), 2);
+    assert_eq!(wordle.closeness("stats"), 2);
+    assert_eq!(wordle.closeness("lynch"), 0);
+    assert_eq!(wordle.closeness("those"), wordle.present_letters().len());
+  }
+
+  #[test]
+  fn it_should_floor_the_guesses_left_by_the_patterns_available() {
+    let five_letter: Vec<String> = ('a'..='z').map(|c| format!("{}aker", c)).collect();
+    assert_eq!(Wordle::new(five_letter).min_guesses_lower_bound(), 1);
+
+    // Two-letter words get 9 patterns a guess, so 26 words need at least two guesses
+    let two_letter: Vec<String> = ('a'..='z').map(|c| format!("{}a", c)).collect();
+    assert_eq!(Wordle::new(two_letter).min_guesses_lower_bound(), 2);
+
+    assert_eq!(Wordle::new(vec![]).min_guesses_lower_bound(), 0);
+  }
+
+  #[test]
+  fn it_should_need_two_guesses_to_be_sure_between_two_candidates() {
+    let pair = Wordle::new(words(&["baker", "faker"]));
+    assert_eq!(pair.guesses_to_guaranteed_solve(), Some(2));
+
+    // Each guess only rules out itself, so the worst case tries all four in turn
+    let family = Wordle::new(words(&["baker", "caker", "daker", "faker"]));
+    assert_eq!(family.guesses_to_guaranteed_solve(), Some(4));
+
+    assert_eq!(Wordle::new(words(&["baker"])).guesses_to_guaranteed_solve(), Some(1));
+    assert_eq!(Wordle::new(vec![]).guesses_to_guaranteed_solve(), Some(0));
+
+    let many: Vec<String> = ('a'..='z').map(|c| format!("{}aker", c)).collect();
+    assert_eq!(Wordle::new(many).guesses_to_guaranteed_solve(), None);
+  }
+
+  #[test]
+  fn it_should_settle_for_an_achievable_worst_case_when_the_budget_runs_out() {
+    let wordle = Wordle::new(words(&["salty", "those", "shirt", "skirt", "lynch", "geese"]));
+    let tight = ComputeBudget {
+      max_nodes: 1,
+      time_limit: None,
+    };
+
+    let (exact, exhaustive) = wordle.guesses_to_guaranteed_solve_within(ComputeBudget::default());
+    assert!(exhaustive);
+    assert_eq!(Some(exact), wordle.guesses_to_guaranteed_solve());
+
+    let (rough, exhaustive) = wordle.guesses_to_guaranteed_solve_within(tight);
+    assert!(!exhaustive);
+    assert!(rough >= exact && rough <= wordle.dictionary.len());
+
+    // Too many to search exactly, but a tight budget still answers straight away
+    let many: Vec<String> = ('a'..='z').map(|c| format!("{}aker", c)).collect();
+    assert_eq!(Wordle::new(many).guesses_to_guaranteed_solve_within(tight), (26, false));
+  }
+
+  #[test]
+  fn it_should_flag_a_ruled_out_guess_that_told_nothing_new() {
+    let mut wordle = Wordle::new(words(&["baker", "caker", "daker", "faker", "shirt", "bcdfz"]));
+
+    for word in ["baker", "shirt", "bcdfz", "faker"] {
+      wordle
+        .add_guess(Guess {
+          guess: String::from(word),
+          result: check_guess(word, "faker"),
+        })
+        .unwrap();
+    }
+
+    assert_eq!(wordle.wasted_guesses(), vec![1]);
+  }
+
+  #[test]
+  fn it_should_score_tightness_from_nothing_known_to_solved() {
+    let mut wordle = Wordle::new(words(&["shirt", "skirt", "salty", "those"]));
+    assert_eq!(wordle.tightness(), 0.0);
+
+    wordle
+      .add_guess(Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "shirt"),
+      })
+      .unwrap();
+    let partial = wordle.tightness();
+    assert!(partial > 0.0 && partial < 0.5);
+
+    wordle
+      .add_guess(Guess {
+        guess: String::from("shirt"),
+        result: check_guess("shirt", "shirt"),
+      })
+      .unwrap();
+    assert_eq!(wordle.tightness(), 1.0);
+  }
+
+  #[test]
+  fn it_should_dump_every_kind_of_constraint() {
+    let mut wordle = Wordle::new(vec![String::from("those")]);
+    wordle
+      .add_guess(Guess {
+        guess: String::from("sheet"),
+        result: check_guess("sheet", "those"),
+      })
+      .unwrap();
+
+    let dump = wordle.constraint_debug();
+
+    assert!(dump.contains("greens: 'h' at 1"));
+    assert!(dump.contains("yellows: 'e' not at 2/3, 's' not at 0, 't' not at 4"));
+    assert!(dump.contains("grays: none"));
+    assert!(dump.contains("'e' 1..=1"));
+    assert_eq!(Wordle::new(vec![]).constraint_debug().matches("none").count(), 4);
+  }
+
+  #[test]
+  fn it_should_list_only_permutations_once_every_letter_is_known() {
+    let mut wordle = Wordle::new(words(&["least", "tales", "teals", "stake", "tealsy"]));
+    wordle
+      .add_guess(Guess {
+        guess: String::from("steal"),
+        result: check_guess("steal", "least"),
+      })
+      .unwrap();
+
+    assert!(wordle.dictionary.contains(&String::from("tealsy")));
+    assert_eq!(wordle.anagram_candidates(), vec!["least", "tales", "teals"]);
+  }
+}
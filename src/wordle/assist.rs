@@ -0,0 +1,101 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use super::{Correctness, GuessError, Strategy, Wordle};
+
+/// What the assist thread suggests after each round of feedback.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NextSuggestion {
+  /// `None` once the puzzle is solved or nothing is left to guess
+  pub suggestion: Option<String>,
+  pub remaining: usize,
+  pub solved: bool,
+  /// Why the last feedback was rejected, in which case nothing changed and the previous
+  /// suggestion stands
+  pub error: Option<GuessError>,
+}
+
+/// Runs an entropy solver over `dict` on its own thread so a frontend can stay
+/// responsive. The opening suggestion arrives straight away; after that, each feedback
+/// sent is applied to the latest suggestion, which is remembered rather than worked out
+/// again, and answered with the next one. The thread
+/// stops once the puzzle is solved or either end of the channel is dropped.
+pub fn spawn_assist(dict: Vec<String>) -> (Sender<Vec<Correctness>>, Receiver<NextSuggestion>) {
+  let (feedback_tx, feedback_rx) = mpsc::channel::<Vec<Correctness>>();
+  let (suggestion_tx, suggestion_rx) = mpsc::channel();
+
+  thread::spawn(move || {
+    // Built here since a Wordle can't be sent between threads
+    let mut wordle = Wordle::new(dict);
+    let opening = NextSuggestion {
+      suggestion: wordle.suggest(Strategy::Entropy),
+      remaining: wordle.dictionary.len(),
+      solved: false,
+      error: None,
+    };
+    if suggestion_tx.send(opening).is_err() {
+      return;
+    }
+
+    for feedback in feedback_rx {
+      let next = match wordle.step(feedback, Strategy::Entropy) {
+        Ok(step) => NextSuggestion {
+          suggestion: step.next_suggestion,
+          remaining: step.remaining,
+          solved: step.solved,
+          error: None,
+        },
+        Err(why) => NextSuggestion {
+          suggestion: wordle.last_suggestion().map(String::from),
+          remaining: wordle.dictionary.len(),
+          solved: false,
+          error: Some(why),
+        },
+      };
+
+      let solved = next.solved;
+      if suggestion_tx.send(next).is_err() || solved {
+        return;
+      }
+    }
+  });
+
+  (feedback_tx, suggestion_rx)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::check_guess;
+
+  #[test]
+  fn it_should_answer_scripted_feedback_with_suggestions() {
+    let dict = words(&["bcdfz", "baker", "caker", "daker", "faker"]);
+    let (feedback, suggestions) = spawn_assist(dict);
+
+    let opening = suggestions.recv().unwrap();
+    let first = opening.suggestion.unwrap();
+    assert_eq!(opening.remaining, 5);
+
+    feedback.send(vec![Correctness::Correct]).unwrap();
+    let rejected = suggestions.recv().unwrap();
+    assert!(rejected.error.is_some());
+    assert_eq!(rejected.suggestion.as_deref(), Some(first.as_str()));
+
+    let mut collected = vec![first.clone()];
+    feedback.send(check_guess(&first, "daker")).unwrap();
+    loop {
+      let next = suggestions.recv().unwrap();
+      if next.solved {
+        break;
+      }
+      let word = next.suggestion.unwrap();
+      feedback.send(check_guess(&word, "daker")).unwrap();
+      collected.push(word);
+    }
+
+    assert_eq!(collected.last().unwrap(), "daker");
+    assert!(suggestions.recv().is_err());
+  }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use fixedbitset::FixedBitSet;
+
+use super::Wordle;
+
+/// A set of candidates stored as one bit per dictionary index, so narrowing and
+/// cloning during lookahead don't copy any words.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateSet {
+  dictionary: Rc<Vec<String>>,
+  // Where each word sits in `dictionary`, shared by every set narrowed from the same one
+  index: Rc<HashMap<String, usize>>,
+  members: FixedBitSet,
+}
+
+impl CandidateSet {
+  /// Every word in `dictionary`.
+  pub fn full(dictionary: Rc<Vec<String>>) -> CandidateSet {
+    let mut members = FixedBitSet::with_capacity(dictionary.len());
+    members.insert_range(..);
+
+    let mut index = HashMap::with_capacity(dictionary.len());
+    for (i, word) in dictionary.iter().enumerate() {
+      index.entry(word.clone()).or_insert(i);
+    }
+
+    CandidateSet {
+      dictionary,
+      index: Rc::new(index),
+      members,
+    }
+  }
+
+  /// The words of this set that satisfy `predicate`.
+  pub fn filter<F>(&self, predicate: F) -> CandidateSet
+  where
+    F: Fn(&str) -> bool,
+  {
+    let mut members = self.members.clone();
+    for i in self.members.ones() {
+      if !predicate(&self.dictionary[i]) {
+        members.set(i, false);
+      }
+    }
+
+    CandidateSet {
+      dictionary: Rc::clone(&self.dictionary),
+      index: Rc::clone(&self.index),
+      members,
+    }
+  }
+
+  // Both sets must index the same dictionary for the bits to line up
+  pub fn intersect(&mut self, other: &CandidateSet) {
+    assert!(
+      Rc::ptr_eq(&self.dictionary, &other.dictionary),
+      "Candidate sets are over different dictionaries"
+    );
+    self.members.intersect_with(&other.members);
+  }
+
+  pub fn contains(&self, word: &str) -> bool {
+    self.index.get(word).is_some_and(|&i| self.members.contains(i))
+  }
+
+  pub fn len(&self) -> usize {
+    self.members.count_ones(..)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.members.is_clear()
+  }
+
+  /// The member words, in dictionary order.
+  pub fn iter_words(&self) -> impl Iterator<Item = &String> + '_ {
+    self.members.ones().map(move |i| &self.dictionary[i])
+  }
+}
+
+impl Wordle {
+  /// The current candidates as a bitset over the full dictionary.
+  pub fn candidate_set(&self) -> CandidateSet {
+    let constraints = &self.constraints;
+    let guesses = &self.guesses;
+
+    CandidateSet::full(Rc::clone(&self.full_dictionary))
+      .filter(|word| constraints.allows(word) && !guesses.iter().any(|g| g.guess == word))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::{check_guess, Guess};
+
+  fn dictionary() -> Vec<String> {
+    words(&["salty", "shirt", "skirt", "lynch", "those", "stare"])
+  }
+
+  #[test]
+  fn it_should_filter_the_same_words_as_the_dictionary() {
+    let mut wordle = Wordle::new(dictionary());
+    for word in ["salty", "stare"] {
+      wordle
+        .add_guess(Guess {
+          guess: String::from(word),
+          result: check_guess(word, "shirt"),
+        })
+        .unwrap();
+    }
+
+    let set = wordle.candidate_set();
+
+    assert_eq!(set.iter_words().cloned().collect::<Vec<_>>(), wordle.dictionary);
+    assert_eq!(set.len(), wordle.dictionary.len());
+  }
+
+  #[test]
+  fn it_should_intersect_sets_over_the_same_dictionary() {
+    let full = CandidateSet::full(Rc::new(dictionary()));
+    let mut with_s = full.filter(|w| w.starts_with('s'));
+    let with_t = full.filter(|w| w.contains('t'));
+
+    with_s.intersect(&with_t);
+
+    let words: Vec<&String> = with_s.iter_words().collect();
+    assert_eq!(words, vec!["salty", "shirt", "skirt", "stare"]);
+    assert!(with_s.contains("skirt"));
+    assert!(!with_s.contains("those"));
+    assert!(!with_s.contains("zzzzz"));
+    assert!(full.filter(|_| false).is_empty());
+  }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use super::{
+  best_opener, english_alphabet, load_dictionary, read_lines_from_file, WordList, STDIN_PATH,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+  // `STDIN_PATH` to read the word list from stdin
+  pub wordfile: String,
+  pub init_guess: String,
+  pub target: String,
+  // Stop and list the candidates once this many or fewer remain; 0 never stops. Run on a
+  // terminal, the binary pauses on the list instead and carries on if asked
+  pub reveal_threshold: usize,
+  // List at most this many candidates when stopping, most likely first
+  pub max_list: Option<usize>,
+  pub allow_punctuation: bool,
+  pub alphabet: Vec<char>,
+  // Sort the dictionary alphabetically on load instead of keeping file order, so
+  // anything that picks "the first" candidate doesn't depend on how the file was written
+  pub sort_dictionary: bool,
+  // Color the guessed letters with ANSI escapes instead of printing emoji squares
+  pub color: bool,
+  // Print the chance each guess has of being the answer
+  pub verbose: bool,
+  // Open with the entropy-optimal word for the dictionary instead of `init_guess`
+  pub auto_open: bool,
+  // Let `init_guess` differ in length from `target`, scoring surplus positions as gray
+  pub lenient: bool,
+  // A file of words, one per line, never to suggest as guesses
+  pub blocklist_file: Option<String>,
+}
+
+impl Config {
+  pub fn new(wordfile: String, init_guess: String, target: String) -> Config {
+    Config {
+      wordfile,
+      init_guess,
+      target,
+      reveal_threshold: 0,
+      max_list: None,
+      allow_punctuation: false,
+      alphabet: english_alphabet(),
+      sort_dictionary: false,
+      color: false,
+      verbose: false,
+      lenient: false,
+      auto_open: false,
+      blocklist_file: None,
+    }
+  }
+
+  /// Where the word list comes from, for messages: the file name, or "stdin".
+  pub fn wordfile_name(&self) -> &str {
+    if self.wordfile == STDIN_PATH {
+      "stdin"
+    } else {
+      &self.wordfile
+    }
+  }
+
+  pub fn load_dictionary(&self) -> Result<WordList, String> {
+    let path = Path::new(&self.wordfile);
+    let mut word_list = load_dictionary(path, &self.alphabet, self.allow_punctuation)?;
+
+    if self.sort_dictionary {
+      word_list.words.sort();
+    }
+
+    Ok(word_list)
+  }
+
+  /// The words in `blocklist_file`, or none if there isn't one. Blank lines are ignored.
+  pub fn load_blocklist(&self) -> Result<HashSet<String>, String> {
+    let path = match &self.blocklist_file {
+      Some(path) => Path::new(path),
+      None => return Ok(HashSet::new()),
+    };
+
+    Ok(read_lines_from_file(path)?
+      .into_iter()
+      .map(|line| line.trim().to_string())
+      .filter(|word| !word.is_empty())
+      .collect())
+  }
+
+  /// The first word to play: `init_guess`, or the best opener for `dictionary` when
+  /// `auto_open` is set.
+  pub fn opening_guess(&self, dictionary: &[String]) -> String {
+    if self.auto_open {
+      if let Some(opener) = best_opener(dictionary) {
+        return opener;
+      }
+    }
+
+    self.init_guess.clone()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use std::fs;
+
+  #[test]
+  fn it_should_only_reorder_the_dictionary_when_sorting_is_enabled() {
+    let name = format!("wordle_solver_{}_scrambled.txt", std::process::id());
+    let path = std::env::temp_dir().join(name);
+    fs::write(&path, "salty\nbaker\nshirt\n").unwrap();
+
+    let wordfile = path.to_string_lossy().into_owned();
+    let mut config = Config::new(wordfile, String::new(), String::new());
+    let unsorted = config.load_dictionary().unwrap().words;
+    config.sort_dictionary = true;
+    let sorted = config.load_dictionary().unwrap().words;
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(unsorted.first().unwrap(), "salty");
+    assert_eq!(sorted.first().unwrap(), "baker");
+    assert_eq!(sorted, vec!["baker", "salty", "shirt"]);
+  }
+
+  #[test]
+  fn it_should_open_with_the_best_opener_when_auto_open_is_set() {
+    let dictionary = words(&["baker", "caker", "daker", "faker", "shirt"]);
+    let mut config = Config::new(String::new(), String::from("shirt"), String::new());
+
+    assert_eq!(config.opening_guess(&dictionary), "shirt");
+
+    config.auto_open = true;
+    assert_eq!(Some(config.opening_guess(&dictionary)), best_opener(&dictionary));
+  }
+
+  #[test]
+  fn it_should_load_the_blocklist_from_a_file() {
+    let name = format!("wordle_solver_{}_blocklist.txt", std::process::id());
+    let path = std::env::temp_dir().join(name);
+    fs::write(&path, "salty\n\n shirt \n").unwrap();
+
+    let mut config = Config::new(String::new(), String::new(), String::new());
+    assert!(config.load_blocklist().unwrap().is_empty());
+
+    config.blocklist_file = Some(path.to_string_lossy().into_owned());
+    let blocklist = config.load_blocklist().unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(blocklist.len(), 2);
+    assert!(blocklist.contains("salty") && blocklist.contains("shirt"));
+  }
+
+  #[test]
+  fn it_should_name_stdin_when_reading_the_word_list_from_it() {
+    let piped = Config::new(String::from(STDIN_PATH), String::new(), String::new());
+    let file = Config::new(String::from("words.txt"), String::new(), String::new());
+
+    assert_eq!(piped.wordfile_name(), "stdin");
+    assert_eq!(file.wordfile_name(), "words.txt");
+  }
+}
@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+
+use super::{ConstraintConflict, Correctness, Guess};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Constraints {
+  pub incorrect_letters: Vec<char>,
+  pub correct_letters: Vec<(char, u32)>,
+  pub misplaced_letters: Vec<char>,
+  // Positions a letter is known not to be in: where it was yellow, or gray while another
+  // copy of it was marked
+  pub excluded_positions: Vec<(char, u32)>,
+  pub min_counts: BTreeMap<char, usize>,
+  // Only known once a letter has come back gray, which caps it at the copies marked
+  pub max_counts: BTreeMap<char, usize>,
+}
+
+/// The first constraint a word fails, as reported by `Wordle::elimination_reason`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+  GreenMismatch { position: usize, expected: char },
+  AbsentLetter(char),
+  MissingLetter(char),
+  ExcludedPosition { letter: char, position: usize },
+  TooFew { letter: char, min: usize },
+  TooMany { letter: char, max: usize },
+  AlreadyGuessed,
+  NotInDictionary,
+}
+
+/// The kind of constraint a `Reason` comes from, for tallying eliminations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConstraintKind {
+  Green,
+  Yellow,
+  Gray,
+  Count,
+  AlreadyGuessed,
+  NotInDictionary,
+}
+
+impl Reason {
+  pub fn kind(&self) -> ConstraintKind {
+    match self {
+      Reason::GreenMismatch { .. } => ConstraintKind::Green,
+      Reason::MissingLetter(_) | Reason::ExcludedPosition { .. } => ConstraintKind::Yellow,
+      Reason::AbsentLetter(_) => ConstraintKind::Gray,
+      Reason::TooFew { .. } | Reason::TooMany { .. } => ConstraintKind::Count,
+      Reason::AlreadyGuessed => ConstraintKind::AlreadyGuessed,
+      Reason::NotInDictionary => ConstraintKind::NotInDictionary,
+    }
+  }
+}
+
+impl Constraints {
+  pub fn add_guess(&mut self, guess: &Guess) {
+    let annotated = guess.annotated();
+
+    for (i, (c, r)) in annotated.iter().copied().enumerate() {
+      if matches!(r, Correctness::Correct) {
+        self.correct_letters.push((c, i.try_into().unwrap()));
+      } else if matches!(r, Correctness::IncorrectPlacement) {
+        self.misplaced_letters.push(c);
+        self.excluded_positions.push((c, i.try_into().unwrap()));
+      } else if !guess.has_letter_marked(c) {
+        self.incorrect_letters.push(c)
+      } else {
+        // A gray copy of a letter that's green or yellow elsewhere in the same guess
+        // only means the word has fewer copies, not that the letter is absent
+        self.excluded_positions.push((c, i.try_into().unwrap()));
+      }
+    }
+
+    for (c, _) in &annotated {
+      let marked = annotated
+        .iter()
+        .filter(|(l, r)| l == c && !matches!(r, Correctness::Incorrect))
+        .count();
+
+      let min = self.min_counts.entry(*c).or_insert(0);
+      *min = (*min).max(marked);
+
+      if annotated.iter().any(|(l, r)| l == c && matches!(r, Correctness::Incorrect)) {
+        let max = self.max_counts.entry(*c).or_insert(marked);
+        *max = (*max).min(marked);
+      }
+    }
+
+    self.min_counts.retain(|_, min| *min > 0);
+    self.normalize();
+  }
+
+  // Sorted and without repeats, so the same guesses give equal constraints in any order
+  fn normalize(&mut self) {
+    for letters in [&mut self.incorrect_letters, &mut self.misplaced_letters] {
+      letters.sort_unstable();
+      letters.dedup();
+    }
+    for placed in [&mut self.correct_letters, &mut self.excluded_positions] {
+      placed.sort_unstable_by_key(|(c, i)| (*i, *c));
+      placed.dedup();
+    }
+  }
+
+  pub fn allows(&self, word: &str) -> bool {
+    self.violation(word).is_none()
+  }
+
+  /// The first constraint `word` breaks, if any.
+  pub fn violation(&self, word: &str) -> Option<Reason> {
+    let chars: Vec<char> = word.chars().collect();
+
+    for (c, i) in &self.correct_letters {
+      if chars.get(*i as usize) != Some(c) {
+        return Some(Reason::GreenMismatch {
+          position: *i as usize,
+          expected: *c,
+        });
+      }
+    }
+
+    for c in &self.incorrect_letters {
+      if chars.contains(c) {
+        return Some(Reason::AbsentLetter(*c));
+      }
+    }
+
+    for c in &self.misplaced_letters {
+      if !chars.contains(c) {
+        return Some(Reason::MissingLetter(*c));
+      }
+    }
+
+    for (c, i) in &self.excluded_positions {
+      if chars.get(*i as usize) == Some(c) {
+        return Some(Reason::ExcludedPosition {
+          letter: *c,
+          position: *i as usize,
+        });
+      }
+    }
+
+    for (c, min) in &self.min_counts {
+      if chars.iter().filter(|l| *l == c).count() < *min {
+        return Some(Reason::TooFew { letter: *c, min: *min });
+      }
+    }
+
+    for (c, max) in &self.max_counts {
+      if chars.iter().filter(|l| *l == c).count() > *max {
+        return Some(Reason::TooMany { letter: *c, max: *max });
+      }
+    }
+
+    None
+  }
+
+  /// Pools what `other` knows into these constraints: the union of greens, yellows and
+  /// grays and the tighter of each count bound. Nothing changes if the combination
+  /// contradicts itself.
+  pub fn merge(&mut self, other: &Constraints) -> Result<(), ConstraintConflict> {
+    let mut merged = self.clone();
+
+    extend_unique(&mut merged.incorrect_letters, &other.incorrect_letters);
+    extend_unique(&mut merged.correct_letters, &other.correct_letters);
+    extend_unique(&mut merged.misplaced_letters, &other.misplaced_letters);
+    extend_unique(&mut merged.excluded_positions, &other.excluded_positions);
+
+    for (c, min) in &other.min_counts {
+      let entry = merged.min_counts.entry(*c).or_insert(0);
+      *entry = (*entry).max(*min);
+    }
+    for (c, max) in &other.max_counts {
+      let entry = merged.max_counts.entry(*c).or_insert(*max);
+      *entry = (*entry).min(*max);
+    }
+
+    merged.normalize();
+    merged.check_consistent()?;
+    *self = merged;
+
+    Ok(())
+  }
+
+  fn check_consistent(&self) -> Result<(), ConstraintConflict> {
+    for (c, i) in &self.correct_letters {
+      if let Some((other, _)) = self.correct_letters.iter().find(|(o, j)| j == i && o != c) {
+        return Err(ConstraintConflict::Position {
+          position: *i as usize,
+          letters: (*c, *other),
+        });
+      }
+
+      if self.excluded_positions.contains(&(*c, *i)) {
+        return Err(ConstraintConflict::Excluded {
+          letter: *c,
+          position: *i as usize,
+        });
+      }
+    }
+
+    for (c, min) in &self.min_counts {
+      // A letter only ever seen gray is capped at zero even without a max_counts entry
+      let max = match self.max_counts.get(c) {
+        Some(max) => *max,
+        None if self.incorrect_letters.contains(c) => 0,
+        None => continue,
+      };
+
+      if *min > max {
+        return Err(ConstraintConflict::Count {
+          letter: *c,
+          min: *min,
+          max,
+        });
+      }
+    }
+
+    Ok(())
+  }
+}
+
+fn extend_unique<T: PartialEq + Copy>(existing: &mut Vec<T>, extra: &[T]) {
+  for item in extra {
+    if !existing.contains(item) {
+      existing.push(*item);
+    }
+  }
+}
+
+/// Rebuilds the constraints implied by `guesses` from scratch.
+pub fn derive_constraints(guesses: &[Guess]) -> Constraints {
+  let mut constraints = Constraints::default();
+
+  for guess in guesses {
+    constraints.add_guess(guess);
+  }
+
+  constraints
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::{check_guess, Wordle};
+
+  fn guess(word: &str, answer: &str) -> Guess {
+    Guess {
+      guess: String::from(word),
+      result: check_guess(word, answer),
+    }
+  }
+
+  fn dictionary() -> Vec<String> {
+    words(&["salty", "shirt", "skirt", "lynch", "those"])
+  }
+
+  #[test]
+  fn it_should_derive_the_same_constraints_as_incremental_guesses() {
+    let mut wordle = Wordle::new(dictionary());
+    wordle.add_guess(guess("lynch", "shirt")).unwrap();
+    wordle.add_guess(guess("geese", "shirt")).unwrap();
+
+    let derived = derive_constraints(&wordle.guesses);
+
+    assert_eq!(derived, wordle.constraints);
+    assert_eq!(derived.incorrect_letters, vec!['c', 'e', 'g', 'l', 'n', 'y']);
+    assert_eq!(derived.misplaced_letters, vec!['h', 's']);
+  }
+
+  #[test]
+  fn it_should_bound_letter_counts_from_repeated_letters() {
+    let constraints = derive_constraints(&[guess("speed", "abide")]);
+
+    assert_eq!(constraints.min_counts.get(&'e'), Some(&1));
+    assert_eq!(constraints.max_counts.get(&'e'), Some(&1));
+    assert_eq!(constraints.max_counts.get(&'s'), Some(&0));
+    assert!(constraints.excluded_positions.contains(&('e', 3)));
+    assert!(constraints.allows("abide"));
+    assert_eq!(
+      constraints.violation("eedue"),
+      Some(Reason::TooMany { letter: 'e', max: 1 })
+    );
+  }
+
+  #[test]
+  fn it_should_restore_candidates_when_undoing_a_guess() {
+    let mut wordle = Wordle::new(dictionary());
+    wordle.add_guess(guess("salty", "shirt")).unwrap();
+    let after_first = wordle.dictionary.clone();
+    wordle.add_guess(guess("skirt", "shirt")).unwrap();
+
+    let undone = wordle.undo_last_guess().unwrap();
+
+    assert_eq!(undone.guess, "skirt");
+    assert_eq!(wordle.dictionary, after_first);
+    assert_eq!(wordle.constraints, derive_constraints(&wordle.guesses));
+
+    wordle.undo_last_guess().unwrap();
+    assert_eq!(wordle.dictionary, dictionary());
+    assert!(wordle.undo_last_guess().is_none());
+  }
+
+  #[test]
+  fn it_should_name_the_position_of_a_green_mismatch() {
+    let mut wordle = Wordle::new(dictionary());
+    wordle.add_guess(guess("skirt", "shirt")).unwrap();
+
+    assert_eq!(
+      wordle.elimination_reason("salty"),
+      Some(Reason::GreenMismatch {
+        position: 2,
+        expected: 'i'
+      })
+    );
+    assert_eq!(wordle.elimination_reason("skirt"), Some(Reason::AbsentLetter('k')));
+    assert_eq!(wordle.elimination_reason("swirt"), Some(Reason::NotInDictionary));
+    assert_eq!(wordle.elimination_reason("shirt"), None);
+  }
+
+  #[test]
+  fn it_should_merge_partial_constraints_into_a_consistent_set() {
+    let mut first = derive_constraints(&[guess("salty", "shirt")]);
+    let second = derive_constraints(&[guess("lynch", "shirt")]);
+    assert!(first.allows("skirt"));
+
+    first.merge(&second).unwrap();
+
+    assert!(first.allows("shirt"));
+    assert_eq!(first.violation("skirt"), Some(Reason::MissingLetter('h')));
+    assert_eq!(first.violation("snort"), Some(Reason::AbsentLetter('n')));
+  }
+
+  #[test]
+  fn it_should_flag_constraints_that_contradict_each_other() {
+    let mut first = derive_constraints(&[guess("salty", "shirt")]);
+    let before = first.clone();
+    let second = derive_constraints(&[guess("shirt", "those")]);
+
+    let conflict = first.merge(&second).unwrap_err();
+
+    assert_eq!(
+      conflict,
+      ConstraintConflict::Excluded {
+        letter: 's',
+        position: 0
+      }
+    );
+    assert_eq!(first, before);
+  }
+}
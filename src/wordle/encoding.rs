@@ -0,0 +1,173 @@
+use super::{Correctness, GREEN_SQUARE, WHITE_SQUARE, YELLOW_SQUARE};
+
+/// Encodes feedback as one letter per tile: `G` green, `Y` yellow, `X` gray.
+pub fn to_gyx(result: &[Correctness]) -> String {
+  result
+    .iter()
+    .map(|r| match r {
+      Correctness::Correct => 'G',
+      Correctness::IncorrectPlacement => 'Y',
+      Correctness::Incorrect => 'X',
+    })
+    .collect()
+}
+
+/// Parses `to_gyx` output, case-insensitively. Returns `None` on any other character.
+pub fn parse_gyx(code: &str) -> Option<Vec<Correctness>> {
+  code
+    .chars()
+    .map(|c| match c.to_ascii_uppercase() {
+      'G' => Some(Correctness::Correct),
+      'Y' => Some(Correctness::IncorrectPlacement),
+      'X' => Some(Correctness::Incorrect),
+      _ => None,
+    })
+    .collect()
+}
+
+/// The character drawn for each kind of tile.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GlyphSet {
+  pub correct: char,
+  pub misplaced: char,
+  pub incorrect: char,
+}
+
+/// The emoji squares shared after a game.
+pub const DEFAULT_GLYPHS: GlyphSet = GlyphSet {
+  correct: GREEN_SQUARE,
+  misplaced: YELLOW_SQUARE,
+  incorrect: WHITE_SQUARE,
+};
+
+impl Default for GlyphSet {
+  fn default() -> GlyphSet {
+    DEFAULT_GLYPHS
+  }
+}
+
+impl GlyphSet {
+  pub fn glyph(&self, result: Correctness) -> char {
+    match result {
+      Correctness::Correct => self.correct,
+      Correctness::IncorrectPlacement => self.misplaced,
+      Correctness::Incorrect => self.incorrect,
+    }
+  }
+
+  pub fn render(&self, result: &[Correctness]) -> String {
+    result.iter().map(|r| self.glyph(*r)).collect()
+  }
+}
+
+/// Renders feedback with `DEFAULT_GLYPHS`.
+pub fn to_emoji(result: &[Correctness]) -> String {
+  DEFAULT_GLYPHS.render(result)
+}
+
+/// Parses `to_emoji` output, also accepting the black square dark mode uses for gray.
+pub fn parse_emoji(code: &str) -> Option<Vec<Correctness>> {
+  code
+    .chars()
+    .map(|c| match c {
+      GREEN_SQUARE => Some(Correctness::Correct),
+      YELLOW_SQUARE => Some(Correctness::IncorrectPlacement),
+      WHITE_SQUARE | '\u{2B1B}' => Some(Correctness::Incorrect),
+      _ => None,
+    })
+    .collect()
+}
+
+// Base-3 codes read like a number with the first tile as the most significant digit:
+// 0 gray, 1 yellow, 2 green. Five tiles need at most 3^5 - 1 = 242, so they fit in a u8.
+const MAX_BASE3_TILES: usize = 5;
+
+impl Correctness {
+  /// Decodes a `to_base3` code back into `len` tiles.
+  pub fn from_base3(code: u8, len: usize) -> Vec<Correctness> {
+    let mut code = code;
+    let mut result = vec![Correctness::Incorrect; len];
+
+    for r in result.iter_mut().rev() {
+      *r = match code % 3 {
+        2 => Correctness::Correct,
+        1 => Correctness::IncorrectPlacement,
+        _ => Correctness::Incorrect,
+      };
+      code /= 3;
+    }
+
+    result
+  }
+
+  /// Packs feedback of up to five tiles into one number. Panics on longer feedback.
+  pub fn to_base3(result: &[Correctness]) -> u8 {
+    assert!(
+      result.len() <= MAX_BASE3_TILES,
+      "Only feedback of up to {} tiles fits in a base-3 code",
+      MAX_BASE3_TILES
+    );
+
+    result.iter().fold(0, |code, r| {
+      let digit = match r {
+        Correctness::Correct => 2,
+        Correctness::IncorrectPlacement => 1,
+        Correctness::Incorrect => 0,
+      };
+      code * 3 + digit
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::check_guess;
+
+  #[test]
+  fn it_should_round_trip_gyx_codes() {
+    let result = check_guess("speed", "abide");
+
+    assert_eq!(to_gyx(&result), "XXYXY");
+    assert_eq!(parse_gyx("xxYxy").unwrap(), result);
+    assert!(parse_gyx("XXZXY").is_none());
+  }
+
+  #[test]
+  fn it_should_default_to_the_green_yellow_and_white_squares() {
+    let glyphs = GlyphSet::default();
+
+    assert_eq!(glyphs.correct, '\u{1F7E9}');
+    assert_eq!(glyphs.misplaced, '\u{1F7E8}');
+    assert_eq!(glyphs.incorrect, '\u{2B1C}');
+    assert_eq!(glyphs, DEFAULT_GLYPHS);
+  }
+
+  #[test]
+  fn it_should_render_with_custom_glyphs() {
+    let glyphs = GlyphSet {
+      correct: 'G',
+      misplaced: 'Y',
+      incorrect: '.',
+    };
+
+    assert_eq!(glyphs.render(&check_guess("speed", "abide")), "..Y.Y");
+  }
+
+  #[test]
+  fn it_should_round_trip_across_every_encoding() {
+    let result = check_guess("speed", "abide");
+
+    let emoji = to_emoji(&result);
+    let code = Correctness::to_base3(&result);
+
+    // XXYXY is 00101 in base 3
+    assert_eq!(code, 10);
+    assert_eq!(Correctness::from_base3(code, 5), result);
+    assert_eq!(parse_emoji(&emoji).unwrap(), result);
+    assert_eq!(parse_gyx(&to_gyx(&parse_emoji(&emoji).unwrap())).unwrap(), result);
+    assert_eq!(to_emoji(&Correctness::from_base3(code, 5)), emoji);
+    assert_eq!(Correctness::to_base3(&[Correctness::Correct; 5]), 242);
+    assert!(parse_emoji("x").is_none());
+  }
+}
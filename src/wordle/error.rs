@@ -0,0 +1,85 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolverError {
+  Io(String),
+  Parse(String),
+  Guess(GuessError),
+}
+
+impl fmt::Display for SolverError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SolverError::Io(why) => write!(f, "{}", why),
+      SolverError::Parse(why) => write!(f, "{}", why),
+      SolverError::Guess(why) => write!(f, "{}", why),
+    }
+  }
+}
+
+impl std::error::Error for SolverError {}
+
+impl From<GuessError> for SolverError {
+  fn from(err: GuessError) -> SolverError {
+    SolverError::Guess(err)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GuessError {
+  InvalidCharacters(String),
+  // The guess's feedback contradicts feedback already received
+  Inconsistent(ConstraintConflict),
+  // The feedback code has the wrong length or a symbol with no rule
+  InvalidFeedback(String),
+  // There was no suggestion to give feedback on
+  NothingToGuess,
+}
+
+impl fmt::Display for GuessError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      GuessError::InvalidCharacters(guess) => {
+        write!(f, "Guess {:?} contains non-alphabetic characters", guess)
+      }
+      GuessError::Inconsistent(conflict) => write!(f, "Inconsistent feedback: {}", conflict),
+      GuessError::InvalidFeedback(code) => write!(f, "Unrecognised feedback code {:?}", code),
+      GuessError::NothingToGuess => write!(f, "No words left to suggest"),
+    }
+  }
+}
+
+impl std::error::Error for GuessError {}
+
+/// Why two sets of constraints can't both hold, as reported by `Constraints::merge`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstraintConflict {
+  // Two different letters are known to be green at the same position
+  Position { position: usize, letters: (char, char) },
+  // A letter needs more copies than it's allowed
+  Count { letter: char, min: usize, max: usize },
+  // A letter is green at a position it's also known not to be in
+  Excluded { letter: char, position: usize },
+}
+
+impl fmt::Display for ConstraintConflict {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ConstraintConflict::Position { position, letters } => write!(
+        f,
+        "Position {} can't be both {:?} and {:?}",
+        position, letters.0, letters.1
+      ),
+      ConstraintConflict::Count { letter, min, max } => write!(
+        f,
+        "{:?} needs at least {} but at most {} copies",
+        letter, min, max
+      ),
+      ConstraintConflict::Excluded { letter, position } => {
+        write!(f, "{:?} is both placed at and excluded from position {}", letter, position)
+      }
+    }
+  }
+}
+
+impl std::error::Error for ConstraintConflict {}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use super::{is_valid_word, parse_gyx, GuessError, Guess, Wordle};
+
+/// Decides whether `word` could still be the answer given one tile of feedback: the
+/// letter at `position` of `guess` was marked with this rule's symbol.
+pub type TileRule = Box<dyn Fn(&str, &str, usize) -> bool>;
+
+/// Feedback symbols and what each one says about the answer, for Wordle variants with
+/// tiles beyond green, yellow and gray. Codes use one symbol per tile, like GYX codes.
+pub struct FeedbackAlphabet {
+  rules: HashMap<char, TileRule>,
+}
+
+impl Default for FeedbackAlphabet {
+  fn default() -> FeedbackAlphabet {
+    let mut alphabet = FeedbackAlphabet {
+      rules: HashMap::new(),
+    };
+
+    alphabet.register('G', |word, guess, i| letter_at(word, i) == letter_at(guess, i));
+    alphabet.register('Y', |word, guess, i| {
+      let c = letter_at(guess, i);
+      letter_at(word, i) != c && c.is_some_and(|c| word.contains(c))
+    });
+    // Judged one tile at a time, so a gray copy of a letter the guess repeats only rules
+    // out this position rather than the letter
+    alphabet.register('X', |word, guess, i| {
+      let c = letter_at(guess, i);
+      let repeated = guess.chars().enumerate().any(|(j, g)| j != i && Some(g) == c);
+      letter_at(word, i) != c && (repeated || c.is_some_and(|c| !word.contains(c)))
+    });
+
+    alphabet
+  }
+}
+
+impl FeedbackAlphabet {
+  /// Adds `symbol` to the alphabet, replacing any rule it already had.
+  pub fn register<F>(&mut self, symbol: char, rule: F)
+  where
+    F: Fn(&str, &str, usize) -> bool + 'static,
+  {
+    self.rules.insert(symbol.to_ascii_uppercase(), Box::new(rule));
+  }
+
+  /// Whether `word` agrees with every tile of `code` for `guess`.
+  pub fn allows(&self, word: &str, guess: &str, code: &str) -> bool {
+    code.chars().enumerate().all(|(i, symbol)| {
+      self
+        .rules
+        .get(&symbol.to_ascii_uppercase())
+        .is_some_and(|rule| rule(word, guess, i))
+    })
+  }
+
+  fn check_code(&self, guess: &str, code: &str) -> Result<(), GuessError> {
+    if code.chars().count() != guess.chars().count() {
+      return Err(GuessError::InvalidFeedback(code.to_string()));
+    }
+
+    match code.chars().find(|s| !self.rules.contains_key(&s.to_ascii_uppercase())) {
+      Some(_) => Err(GuessError::InvalidFeedback(code.to_string())),
+      None => Ok(()),
+    }
+  }
+}
+
+fn letter_at(word: &str, i: usize) -> Option<char> {
+  word.chars().nth(i)
+}
+
+impl Wordle {
+  /// Narrows the candidates by feedback written in `alphabet`'s symbols. A code of only
+  /// G, Y and X is handled exactly like `add_guess`; any other symbol is applied by its
+  /// rule alone, so that guess isn't kept in `guesses` or the constraints.
+  pub fn add_feedback(
+    &mut self,
+    guess: &str,
+    code: &str,
+    alphabet: &FeedbackAlphabet,
+  ) -> Result<(), GuessError> {
+    if !is_valid_word(guess, &self.alphabet, self.allow_punctuation) {
+      return Err(GuessError::InvalidCharacters(guess.to_string()));
+    }
+    alphabet.check_code(guess, code)?;
+
+    if let Some(result) = parse_gyx(code) {
+      return self.add_guess(Guess {
+        guess: guess.to_string(),
+        result,
+      });
+    }
+
+    self
+      .dictionary
+      .retain(|word| word != guess && alphabet.allows(word, guess, code));
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+
+  fn wordle() -> Wordle {
+    Wordle::new(words(&["geese", "those", "raise", "lease", "sense"]))
+  }
+
+  #[test]
+  fn it_should_apply_a_registered_fourth_tile() {
+    let mut alphabet = FeedbackAlphabet::default();
+    // A toy "blue" tile: the letter appears exactly twice in the answer
+    alphabet.register('B', |word, guess, i| {
+      let c = guess.chars().nth(i).unwrap();
+      word.chars().filter(|w| *w == c).count() == 2
+    });
+
+    let mut wordle = wordle();
+    wordle.add_feedback("salty", "BXXXX", &alphabet).unwrap();
+
+    assert_eq!(wordle.dictionary, vec!["sense"]);
+    assert!(wordle.guesses.is_empty());
+  }
+
+  #[test]
+  fn it_should_treat_standard_codes_like_add_guess() {
+    let mut wordle = wordle();
+    wordle.add_feedback("those", "XXXGG", &FeedbackAlphabet::default()).unwrap();
+
+    assert_eq!(wordle.dictionary, vec!["geese", "raise", "lease", "sense"]);
+    assert_eq!(wordle.guesses.len(), 1);
+  }
+
+  #[test]
+  fn it_should_reject_symbols_the_alphabet_does_not_know() {
+    let mut wordle = wordle();
+    let result = wordle.add_feedback("salty", "BXXXX", &FeedbackAlphabet::default());
+
+    assert_eq!(result, Err(GuessError::InvalidFeedback(String::from("BXXXX"))));
+    assert_eq!(wordle.dictionary.len(), 5);
+  }
+}
@@ -0,0 +1,701 @@
+use std::{
+  collections::{HashMap, HashSet},
+  fs::{self, File},
+  io::{self, prelude::*, BufReader},
+  path::Path,
+};
+
+#[cfg(feature = "json")]
+use super::SolveStats;
+use super::{check_guess, is_valid_word, parse_gyx, to_gyx, Constraints, Guess, SolverError, Wordle};
+
+// Passed as a filename to read from stdin instead
+pub const STDIN_PATH: &str = "-";
+
+/// Reads every line of `filename`, or of stdin if it's `STDIN_PATH`.
+pub fn read_lines_from_file(filename: &Path) -> Result<Vec<String>, String> {
+  if filename == Path::new(STDIN_PATH) {
+    return Ok(read_lines(io::stdin().lock()));
+  }
+
+  let file = File::open(filename);
+  if file.is_err() {
+    return Err(format!("Could not open file {:?}", &filename));
+  }
+
+  Ok(read_lines(BufReader::new(file.unwrap())))
+}
+
+pub fn read_lines(reader: impl BufRead) -> Vec<String> {
+  reader
+    .lines()
+    .map(|l| l.expect("Could not parse line"))
+    .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordList {
+  pub words: Vec<String>,
+  // Empty or whitespace-only lines that were skipped, e.g. a stray one at the end of the file
+  pub blank_lines: usize,
+  // Entries that were dropped for using letters or punctuation the list doesn't allow
+  pub invalid_words: usize,
+}
+
+/// Reads a word list, skipping blank lines and dropping entries with letters outside
+/// `alphabet`. Apostrophes and hyphens are dropped too unless `allow_punctuation` is set,
+/// in which case they're kept as literal characters. A `filename` of `STDIN_PATH` reads
+/// the list from stdin.
+pub fn load_dictionary(
+  filename: &Path,
+  alphabet: &[char],
+  allow_punctuation: bool,
+) -> Result<WordList, String> {
+  let lines = read_lines_from_file(filename)?;
+
+  Ok(word_list_from_lines(lines, alphabet, allow_punctuation))
+}
+
+/// Like `load_dictionary`, but reads the list from `reader`, e.g. a pipe.
+pub fn read_dictionary(
+  reader: impl BufRead,
+  alphabet: &[char],
+  allow_punctuation: bool,
+) -> WordList {
+  word_list_from_lines(read_lines(reader), alphabet, allow_punctuation)
+}
+
+fn word_list_from_lines(
+  lines: Vec<String>,
+  alphabet: &[char],
+  allow_punctuation: bool,
+) -> WordList {
+  let total = lines.len();
+
+  // Trimmed and lowercased first so padded or capitalized entries aren't rejected as
+  // outside the alphabet
+  let mut words: Vec<String> = lines
+    .into_iter()
+    .map(|line| line.trim().to_lowercase())
+    .filter(|word| !word.is_empty())
+    .collect();
+  let blank_lines = total - words.len();
+
+  words.retain(|word| is_valid_word(word, alphabet, allow_punctuation));
+  let invalid_words = total - blank_lines - words.len();
+
+  WordList {
+    words,
+    blank_lines,
+    invalid_words,
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergedDictionaries {
+  pub answers: Vec<String>,
+  pub guesses: Vec<String>,
+  // Answers that weren't in the guess list, so were dropped; one warning each
+  pub missing_answers: Vec<String>,
+  // Entries across both lists that were repeats once case and whitespace were normalized
+  pub duplicates: usize,
+}
+
+/// Lowercases and trims an answer list and a guess list that may use different casing,
+/// dropping blank entries and repeats, then drops any answer that isn't also a valid
+/// guess. Both lists keep their first-seen order.
+pub fn merge_dictionaries(answers: &[String], guesses: &[String]) -> MergedDictionaries {
+  let mut duplicates = 0;
+  let mut normalize = |words: &[String]| -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut normalized = Vec::new();
+
+    for word in words.iter().map(|w| w.trim().to_lowercase()).filter(|w| !w.is_empty()) {
+      if seen.insert(word.clone()) {
+        normalized.push(word);
+      } else {
+        duplicates += 1;
+      }
+    }
+
+    normalized
+  };
+
+  let guesses = normalize(guesses);
+  let allowed: HashSet<&String> = guesses.iter().collect();
+  let (answers, missing_answers): (Vec<String>, Vec<String>) = normalize(answers)
+    .into_iter()
+    .partition(|answer| allowed.contains(answer));
+
+  MergedDictionaries {
+    answers,
+    guesses,
+    missing_answers,
+    duplicates,
+  }
+}
+
+/// Streams `path` and keeps only the words `constraints` allows, without holding the
+/// rest of the file in memory.
+pub fn filter_file(path: &Path, constraints: &Constraints) -> io::Result<Vec<String>> {
+  let buf = BufReader::new(File::open(path)?);
+  let mut survivors = Vec::new();
+
+  for line in buf.lines() {
+    // Normalized the same way `load_dictionary` normalizes its entries
+    let word = line?.trim().to_lowercase();
+    if word.is_empty() {
+      continue;
+    }
+
+    if constraints.allows(&word) {
+      survivors.push(word);
+    }
+  }
+
+  Ok(survivors)
+}
+
+/// Grades every whitespace-separated guess in `path` against `target`, in order.
+pub fn grade_from_file(path: &Path, target: &str) -> Result<Vec<Guess>, SolverError> {
+  let lines = read_lines_from_file(path).map_err(SolverError::Io)?;
+
+  Ok(lines
+    .iter()
+    .flat_map(|line| line.split_whitespace())
+    .map(|word| Guess {
+      guess: word.to_string(),
+      result: check_guess(word, target),
+    })
+    .collect())
+}
+
+/// Reads known greens, yellows and grays, one per line: `green a 0`, `yellow t 2` or
+/// `gray s`, with positions counted from 0. Blank lines are skipped. Lines may come from
+/// different guesses, so a letter is only known to appear once per distinct green
+/// position, or once at all if it's only ever yellow.
+pub fn load_constraints(path: &Path) -> Result<Constraints, SolverError> {
+  let lines = read_lines_from_file(path).map_err(SolverError::Io)?;
+  let mut constraints = Constraints::default();
+  let mut grays = Vec::new();
+  let mut green_positions: HashMap<char, HashSet<u32>> = HashMap::new();
+
+  for line in lines.iter().filter(|l| !l.trim().is_empty()) {
+    let malformed = || SolverError::Parse(format!("Malformed constraint line {:?}", line));
+
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let mut letters = parts.get(1).ok_or_else(malformed)?.chars();
+    let letter = match (letters.next(), letters.next()) {
+      (Some(c), None) => c,
+      _ => return Err(malformed()),
+    };
+    let position = || -> Result<u32, SolverError> {
+      match parts.get(2..) {
+        Some([position]) => position.parse().map_err(|_| malformed()),
+        _ => Err(malformed()),
+      }
+    };
+
+    match parts[0] {
+      "green" => {
+        let position = position()?;
+        constraints.correct_letters.push((letter, position));
+        green_positions.entry(letter).or_default().insert(position);
+        constraints.min_counts.entry(letter).or_insert(1);
+      }
+      "yellow" => {
+        constraints.misplaced_letters.push(letter);
+        constraints.excluded_positions.push((letter, position()?));
+        constraints.min_counts.entry(letter).or_insert(1);
+      }
+      "gray" if parts.len() == 2 => grays.push(letter),
+      _ => return Err(malformed()),
+    }
+  }
+
+  for (letter, positions) in green_positions {
+    constraints.min_counts.insert(letter, positions.len());
+  }
+
+  // As with a guess, a gray letter that's also green or yellow only caps its count
+  for letter in grays {
+    match constraints.min_counts.get(&letter) {
+      Some(min) => {
+        constraints.max_counts.insert(letter, *min);
+      }
+      None => {
+        constraints.incorrect_letters.push(letter);
+        constraints.max_counts.insert(letter, 0);
+      }
+    }
+  }
+
+  Ok(constraints)
+}
+
+/// Writes `stats` to `path` as a JSON baseline for `compare_to_baseline`.
+#[cfg(feature = "json")]
+pub fn save_baseline(stats: &SolveStats, path: &Path) -> Result<(), SolverError> {
+  let json = serde_json::to_string_pretty(stats)
+    .map_err(|why| SolverError::Parse(format!("Could not encode baseline: {}", why)))?;
+
+  fs::write(path, json)
+    .map_err(|why| SolverError::Io(format!("Could not write file {:?}: {}", path, why)))
+}
+
+/// Reads a baseline written by `save_baseline`.
+#[cfg(feature = "json")]
+pub fn load_baseline(path: &Path) -> Result<SolveStats, SolverError> {
+  let json = fs::read_to_string(path)
+    .map_err(|why| SolverError::Io(format!("Could not open file {:?}: {}", path, why)))?;
+
+  serde_json::from_str(&json)
+    .map_err(|why| SolverError::Parse(format!("Malformed baseline {:?}: {}", path, why)))
+}
+
+/// The words in the first array literal of `js`, as the NYT site bundle embeds its word
+/// lists, e.g. `var Ma=["cigar","rebut"]`. See `parse_nyt_lists` for a bundle holding
+/// both the answer and the allowed array.
+pub fn parse_nyt_list(js: &str) -> Vec<String> {
+  parse_nyt_lists(js).into_iter().next().unwrap_or_default()
+}
+
+/// The words of every array literal in `js` that holds any, in the order they appear, so
+/// the answer and allowed arrays of a bundle come out as separate lists. Strings that
+/// aren't made of letters are skipped, and the rest are lowercased.
+pub fn parse_nyt_lists(js: &str) -> Vec<Vec<String>> {
+  let mut lists = Vec::new();
+  let mut current = Vec::new();
+  let mut depth = 0;
+  let mut chars = js.chars();
+
+  while let Some(c) = chars.next() {
+    match c {
+      '[' => depth += 1,
+      ']' if depth > 0 => {
+        depth -= 1;
+        if depth == 0 && !current.is_empty() {
+          lists.push(std::mem::take(&mut current));
+        }
+      }
+      '"' | '\'' => {
+        let mut literal = String::new();
+        while let Some(next) = chars.next() {
+          match next {
+            '\\' => literal.extend(chars.next()),
+            _ if next == c => break,
+            _ => literal.push(next),
+          }
+        }
+
+        if depth > 0 && !literal.is_empty() && literal.chars().all(char::is_alphabetic) {
+          current.push(literal.to_lowercase());
+        }
+      }
+      _ => {}
+    }
+  }
+
+  lists
+}
+
+/// Rebuilds a session from `Wordle::to_script` output by replaying its guesses over
+/// `dict`.
+pub fn replay_script(dict: Vec<String>, script: &str) -> Result<Wordle, SolverError> {
+  let mut wordle = Wordle::new(dict);
+
+  for line in script.lines().filter(|l| !l.trim().is_empty()) {
+    let mut parts = line.split_whitespace();
+    let (guess, code) = match (parts.next(), parts.next(), parts.next()) {
+      (Some(guess), Some(code), None) => (guess, code),
+      _ => return Err(SolverError::Parse(format!("Malformed state line {:?}", line))),
+    };
+
+    let result = parse_gyx(code)
+      .filter(|r| r.len() == guess.chars().count())
+      .ok_or_else(|| SolverError::Parse(format!("Malformed feedback {:?}", code)))?;
+
+    wordle.add_guess(Guess {
+      guess: guess.to_string(),
+      result,
+    })?;
+  }
+
+  Ok(wordle)
+}
+
+impl Wordle {
+  /// The guesses so far as a script `replay_script` can play back, one
+  /// `<guess> <GYX feedback>` per line.
+  pub fn to_script(&self) -> String {
+    self
+      .guesses
+      .iter()
+      .map(|g| format!("{} {}\n", g.guess, to_gyx(&g.result)))
+      .collect()
+  }
+
+  /// Writes the applied constraints, then `to_script`, to `path`. The constraints from
+  /// the guesses themselves are rebuilt when the state is loaded.
+  pub fn save_state(&self, path: &Path) -> Result<(), SolverError> {
+    let state = applied_constraint_lines(&self.applied_constraints) + &self.to_script();
+
+    fs::write(path, state)
+      .map_err(|why| SolverError::Io(format!("Could not write file {:?}: {}", path, why)))
+  }
+
+  /// Restores a session written by `save_state`, replaying its guesses over `full_dict`
+  /// and applying its saved constraints again.
+  pub fn load_state(path: &Path, full_dict: Vec<String>) -> Result<Wordle, SolverError> {
+    let lines = read_lines_from_file(path).map_err(SolverError::Io)?;
+    let (applied, script): (Vec<&String>, Vec<&String>) =
+      lines.iter().partition(|line| line.starts_with(APPLIED_PREFIX));
+
+    let constraints = parse_applied_constraints(&applied)?;
+    let script: Vec<&str> = script.iter().map(|line| line.as_str()).collect();
+    let mut wordle = replay_script(full_dict, &script.join("\n"))?;
+    if constraints != Constraints::default() {
+      wordle.apply_constraints(&constraints)?;
+    }
+
+    Ok(wordle)
+  }
+}
+
+// Starts each line of a state file that holds an applied constraint rather than a guess
+const APPLIED_PREFIX: &str = "applied ";
+
+// One line per fact in `constraints`, so they read back exactly rather than re-derived
+// the way `load_constraints` derives counts from greens and yellows
+fn applied_constraint_lines(constraints: &Constraints) -> String {
+  let mut facts = Vec::new();
+  facts.extend(constraints.incorrect_letters.iter().map(|c| format!("absent {}", c)));
+  facts.extend(constraints.correct_letters.iter().map(|(c, i)| format!("green {} {}", c, i)));
+  facts.extend(constraints.misplaced_letters.iter().map(|c| format!("present {}", c)));
+  facts.extend(constraints.excluded_positions.iter().map(|(c, i)| format!("excluded {} {}", c, i)));
+  facts.extend(constraints.min_counts.iter().map(|(c, n)| format!("min {} {}", c, n)));
+  facts.extend(constraints.max_counts.iter().map(|(c, n)| format!("max {} {}", c, n)));
+
+  facts.iter().map(|fact| format!("{}{}\n", APPLIED_PREFIX, fact)).collect()
+}
+
+fn parse_applied_constraints(lines: &[&String]) -> Result<Constraints, SolverError> {
+  let mut constraints = Constraints::default();
+
+  for line in lines {
+    let malformed = || SolverError::Parse(format!("Malformed state line {:?}", line));
+
+    let parts: Vec<&str> = line.split_whitespace().skip(1).collect();
+    let mut letters = parts.get(1).ok_or_else(malformed)?.chars();
+    let letter = match (letters.next(), letters.next()) {
+      (Some(c), None) => c,
+      _ => return Err(malformed()),
+    };
+    let number = || -> Result<usize, SolverError> {
+      match parts.get(2..) {
+        Some([number]) => number.parse().map_err(|_| malformed()),
+        _ => Err(malformed()),
+      }
+    };
+
+    match parts[0] {
+      "absent" if parts.len() == 2 => constraints.incorrect_letters.push(letter),
+      "green" => constraints.correct_letters.push((letter, number()? as u32)),
+      "present" if parts.len() == 2 => constraints.misplaced_letters.push(letter),
+      "excluded" => constraints.excluded_positions.push((letter, number()? as u32)),
+      "min" => {
+        constraints.min_counts.insert(letter, number()?);
+      }
+      "max" => {
+        constraints.max_counts.insert(letter, number()?);
+      }
+      _ => return Err(malformed()),
+    }
+  }
+
+  Ok(constraints)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::{derive_constraints, english_alphabet, solve, Correctness, Strategy};
+  use std::{fs, path::PathBuf};
+
+  fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("wordle_solver_{}_{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+  }
+
+  #[test]
+  fn it_should_grade_each_guess_in_the_file() {
+    let path = write_temp_file("grade.txt", "salty skirt\nshirt\n");
+
+    let guesses = grade_from_file(&path, "shirt").unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let words: Vec<_> = guesses.iter().map(|g| g.guess.as_str()).collect();
+    assert_eq!(words, vec!["salty", "skirt", "shirt"]);
+    assert_eq!(guesses[1].result, check_guess("skirt", "shirt"));
+    assert!(guesses[2].result.iter().all(|r| *r == Correctness::Correct));
+  }
+
+  #[test]
+  fn it_should_drop_punctuated_words_by_default() {
+    let path = write_temp_file("punctuation_default.txt", "salty\ndon't\nx-ray\n");
+
+    let words = load_dictionary(&path, &english_alphabet(), false).unwrap().words;
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(words, vec!["salty"]);
+  }
+
+  #[test]
+  fn it_should_keep_punctuated_words_when_allowed() {
+    let path = write_temp_file("punctuation_allowed.txt", "salty\ndon't\nx-ray\nsa1ty\n");
+
+    let words = load_dictionary(&path, &english_alphabet(), true).unwrap().words;
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(words, vec!["salty", "don't", "x-ray"]);
+    assert!(check_guess("don't", "don't").iter().all(|r| *r == Correctness::Correct));
+  }
+
+  #[test]
+  fn it_should_drop_words_outside_a_custom_alphabet() {
+    let path = write_temp_file("alphabet.txt", "вода\nсalty\nдом\n");
+
+    let alphabet: Vec<char> = "адвмо".chars().collect();
+
+    let words = load_dictionary(&path, &alphabet, false).unwrap().words;
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(words, vec!["вода", "дом"]);
+  }
+
+  #[test]
+  fn it_should_lowercase_capitalized_words_instead_of_dropping_them() {
+    let path = write_temp_file("capitalized.txt", "Salty\nSHIRT\nДом\n");
+
+    let alphabet: Vec<char> = english_alphabet().into_iter().chain("дом".chars()).collect();
+    let words = load_dictionary(&path, &alphabet, false).unwrap().words;
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(words, vec!["salty", "shirt", "дом"]);
+  }
+
+  #[test]
+  fn it_should_skip_and_count_blank_lines() {
+    let path = write_temp_file("blank_lines.txt", "salty\n shirt \nsk1rt\n\n");
+
+    let word_list = load_dictionary(&path, &english_alphabet(), false).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(word_list.words, vec!["salty", "shirt"]);
+    assert_eq!(word_list.blank_lines, 1);
+    assert_eq!(word_list.invalid_words, 1);
+  }
+
+  #[test]
+  fn it_should_restore_identical_candidates_from_saved_state() {
+    let dict = words(&["salty", "shirt", "skirt", "lynch", "those"]);
+    let mut wordle = Wordle::new(dict.clone());
+    wordle
+      .add_guess(Guess {
+        guess: String::from("lynch"),
+        result: check_guess("lynch", "shirt"),
+      })
+      .unwrap();
+
+    let path = std::env::temp_dir().join(format!("wordle_solver_{}_state.txt", std::process::id()));
+    wordle.save_state(&path).unwrap();
+    let restored = Wordle::load_state(&path, dict).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(restored.dictionary, wordle.dictionary);
+    assert_eq!(restored.guesses.len(), 1);
+    assert_eq!(restored.constraints, wordle.constraints);
+  }
+
+  #[test]
+  fn it_should_restore_applied_constraints_from_saved_state() {
+    let path = write_temp_file("applied_constraints.txt", "green s 0\ngray a\n");
+    let constraints = load_constraints(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let dict = words(&["salty", "shirt", "skirt", "stink", "lynch"]);
+    let mut wordle = Wordle::new(dict.clone());
+    wordle.apply_constraints(&constraints).unwrap();
+    wordle
+      .add_guess(Guess {
+        guess: String::from("stink"),
+        result: check_guess("stink", "shirt"),
+      })
+      .unwrap();
+
+    let path = write_temp_file("applied_state.txt", "");
+    wordle.save_state(&path).unwrap();
+    let restored = Wordle::load_state(&path, dict).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(restored.dictionary, wordle.dictionary);
+    assert_eq!(restored.applied_constraints, wordle.applied_constraints);
+    assert_eq!(restored.constraints, wordle.constraints);
+  }
+
+  #[test]
+  fn it_should_reject_a_malformed_state_file() {
+    let path = write_temp_file("bad_state.txt", "lynch XXQXY\n");
+    let result = Wordle::load_state(&path, vec![String::from("salty")]);
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(result, Err(SolverError::Parse(_))));
+
+    let path = write_temp_file("bad_applied_state.txt", "applied green s\n");
+    let result = Wordle::load_state(&path, vec![String::from("salty")]);
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(result, Err(SolverError::Parse(_))));
+  }
+
+  #[test]
+  fn it_should_only_return_words_the_constraints_allow() {
+    let path = write_temp_file("stream.txt", "salty\n Shirt \nskirt\n\nlynch\nthose\n");
+    let constraints = derive_constraints(&[Guess {
+      guess: String::from("salty"),
+      result: check_guess("salty", "shirt"),
+    }]);
+
+    let words = filter_file(&path, &constraints).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(words, vec!["shirt", "skirt"]);
+    assert!(filter_file(Path::new("does/not/exist.txt"), &constraints).is_err());
+  }
+
+  #[test]
+  fn it_should_return_an_error_for_a_missing_file() {
+    let result = grade_from_file(Path::new("does/not/exist.txt"), "shirt");
+
+    assert!(matches!(result, Err(SolverError::Io(_))));
+  }
+
+  #[test]
+  fn it_should_narrow_candidates_with_constraints_from_a_file() {
+    let path = write_temp_file("constraints.txt", "green s 0\nyellow t 2\n\ngray a\ngray l\n");
+    let dict = words(&["salty", "shirt", "skirt", "stink", "those"]);
+
+    let constraints = load_constraints(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let mut wordle = Wordle::new(dict);
+    wordle.apply_constraints(&constraints).unwrap();
+
+    assert_eq!(wordle.dictionary, vec!["shirt", "skirt", "stink"]);
+    wordle
+      .add_guess(Guess {
+        guess: String::from("skirt"),
+        result: check_guess("skirt", "shirt"),
+      })
+      .unwrap();
+    assert_eq!(wordle.dictionary, vec!["shirt"]);
+    assert!(wordle.constraints.incorrect_letters.contains(&'a'));
+  }
+
+  #[test]
+  fn it_should_not_double_count_a_letter_seen_by_separate_guesses() {
+    let path = write_temp_file("repeated_constraints.txt", "yellow t 2\nyellow t 3\n");
+    let dict = words(&["those", "tatty", "salty"]);
+
+    let constraints = load_constraints(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(constraints.min_counts.get(&'t'), Some(&1));
+    let mut wordle = Wordle::new(dict);
+    wordle.apply_constraints(&constraints).unwrap();
+    assert_eq!(wordle.dictionary, vec!["those"]);
+
+    let path = write_temp_file("two_greens.txt", "green t 0\ngreen t 3\nyellow t 2\n");
+    let constraints = load_constraints(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(constraints.min_counts.get(&'t'), Some(&2));
+  }
+
+  #[test]
+  fn it_should_reject_a_malformed_constraint_line() {
+    let path = write_temp_file("bad_constraints.txt", "green s\n");
+
+    let result = load_constraints(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+      result,
+      Err(SolverError::Parse(String::from("Malformed constraint line \"green s\"")))
+    );
+  }
+
+  #[test]
+  fn it_should_replay_an_exported_script_to_the_same_candidates() {
+    let dict = words(&["salty", "shirt", "skirt", "lynch", "those", "stink"]);
+    let mut wordle = Wordle::new(dict.clone());
+    solve(&mut wordle, "lynch", "skirt", &Strategy::Entropy).unwrap();
+
+    let script = wordle.to_script();
+    let replayed = replay_script(dict, &script).unwrap();
+
+    assert_eq!(script.lines().count(), wordle.guesses.len());
+    assert_eq!(replayed.dictionary, wordle.dictionary);
+    assert_eq!(replayed.guesses, wordle.guesses);
+  }
+
+  #[cfg(feature = "json")]
+  #[test]
+  fn it_should_round_trip_a_baseline_through_json() {
+    let answers = words(&["salty", "shirt", "skirt"]);
+    let stats = crate::wordle::simulate(&answers, "salty", &Strategy::Entropy, 6);
+    let path = write_temp_file("baseline.json", "");
+
+    save_baseline(&stats, &path).unwrap();
+    let loaded = load_baseline(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, Ok(stats));
+  }
+
+  #[test]
+  fn it_should_normalize_and_reconcile_mixed_case_lists() {
+    let answers = words(&["Shirt", "SKIRT", "shirt", "Zzzzz"]);
+    let guesses = words(&["shirt", " Skirt", "SALTY", "salty", ""]);
+
+    let merged = merge_dictionaries(&answers, &guesses);
+
+    assert_eq!(merged.answers, vec!["shirt", "skirt"]);
+    assert_eq!(merged.guesses, vec!["shirt", "skirt", "salty"]);
+    assert_eq!(merged.missing_answers, vec!["zzzzz"]);
+    assert_eq!(merged.duplicates, 2);
+  }
+
+  #[test]
+  fn it_should_load_a_dictionary_piped_through_a_reader() {
+    let piped = io::Cursor::new("salty\nshirt\n\nsk1rt\n");
+
+    let word_list = read_dictionary(piped, &english_alphabet(), false);
+
+    assert_eq!(word_list.words, vec!["salty", "shirt"]);
+    assert_eq!(word_list.blank_lines, 1);
+    assert_eq!(word_list.invalid_words, 1);
+  }
+
+  #[test]
+  fn it_should_parse_the_answer_and_allowed_arrays_from_a_bundle() {
+    let js = r#"var x="ignored",Ma=["cigar","Rebut",'sissy'],Oa=["aahed","aalii"],n=[1,[2]];"#;
+
+    assert_eq!(parse_nyt_list(js), vec!["cigar", "rebut", "sissy"]);
+    assert_eq!(
+      parse_nyt_lists(js),
+      vec![vec!["cigar", "rebut", "sissy"], vec!["aahed", "aalii"]]
+    );
+    assert!(parse_nyt_list("var n=[1,2];").is_empty());
+  }
+}
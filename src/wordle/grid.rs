@@ -0,0 +1,443 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Correctness, Guess, Wordle};
+
+// Fills the cells a shorter guess doesn't have so every row spans the same columns. A
+// tile rather than a space, so it's as wide as the emoji around it.
+pub const BLANK_CELL: char = '⬛';
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_GREEN: &str = "\x1b[30;42m";
+const ANSI_YELLOW: &str = "\x1b[30;43m";
+const ANSI_GRAY: &str = "\x1b[30;47m";
+
+impl Guess {
+  /// The guessed letters on green/yellow/gray backgrounds using ANSI escapes. Only
+  /// useful on a terminal; callers should fall back to `get_formatted_result` otherwise.
+  pub fn render_colored(&self) -> String {
+    self
+      .annotated()
+      .into_iter()
+      .map(|(c, r)| {
+        let color = match r {
+          Correctness::Correct => ANSI_GREEN,
+          Correctness::IncorrectPlacement => ANSI_YELLOW,
+          Correctness::Incorrect => ANSI_GRAY,
+        };
+        format!("{}{}{}", color, c, ANSI_RESET)
+      })
+      .collect()
+  }
+}
+
+impl Wordle {
+  /// The emoji grid in a fenced code block followed by a one-line summary, ready to paste
+  /// into a GitHub issue or chat message.
+  pub fn to_markdown(&self) -> String {
+    let summary = if self.guesses.is_empty() {
+      String::from("No guesses yet")
+    } else if self.is_solved() {
+      format!("Solved in {} guess(es)", self.guesses.len())
+    } else {
+      format!("Not solved after {} guess(es)", self.guesses.len())
+    };
+
+    let mut markdown = String::from("```\n");
+    for row in self.get_formatted_grid() {
+      markdown.push_str(&row);
+      markdown.push('\n');
+    }
+    markdown.push_str("```\n");
+    markdown.push_str(&summary);
+
+    markdown
+  }
+}
+
+/// How many terminal columns `text` takes up, counting emoji tiles as the two columns most
+/// terminals draw them in. The `unicode` feature measures any text exactly; without it only
+/// the emoji used for tiles are known to be wide.
+pub fn display_width(text: &str) -> usize {
+  #[cfg(feature = "unicode")]
+  {
+    unicode_width::UnicodeWidthStr::width(text)
+  }
+
+  #[cfg(not(feature = "unicode"))]
+  text.chars().map(tile_width).sum()
+}
+
+#[cfg(not(feature = "unicode"))]
+fn tile_width(c: char) -> usize {
+  match c {
+    '\u{2B1B}' | '\u{2B1C}' | '\u{1F300}'..='\u{1FAFF}' => 2,
+    _ => 1,
+  }
+}
+
+/// Renders each guess as a row of tiles, padding short rows to the widest guess.
+pub fn get_formatted_grid(guesses: &[Guess]) -> Vec<String> {
+  let rows: Vec<String> = guesses.iter().map(|g| g.get_formatted_result()).collect();
+  let width = rows.iter().map(|row| display_width(row)).max().unwrap_or(0);
+
+  rows
+    .into_iter()
+    .map(|mut row| {
+      while display_width(&row) < width {
+        row.push(BLANK_CELL);
+      }
+      row
+    })
+    .collect()
+}
+
+pub fn format_candidates(words: &[String]) -> String {
+  words.join("\n")
+}
+
+/// Like `format_candidates`, but lists only the first `max_list` words and sums up the
+/// rest in a final "... and M more" line.
+pub fn format_candidates_capped(words: &[String], max_list: usize) -> String {
+  if words.len() <= max_list {
+    return format_candidates(words);
+  }
+
+  let mut lines: Vec<String> = words[..max_list].to_vec();
+  lines.push(format!("... and {} more", words.len() - max_list));
+  lines.join("\n")
+}
+
+/// One `<word> <score>` line per entry, with scores to two decimal places, e.g. for
+/// `starter_recommendations`.
+pub fn format_scored(words: &[(String, f64)]) -> String {
+  words
+    .iter()
+    .map(|(word, score)| format!("{} {:.2}", word, score))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Checks whether some word of `alphabet` letters could have produced every row of
+/// feedback, i.e. the rows don't contradict each other. Repeated letters are handled the
+/// same way `check_guess` scores them.
+pub fn is_consistent_grid(rows: &[(String, Vec<Correctness>)], alphabet: &[char]) -> bool {
+  let len = match rows.first() {
+    Some((guess, _)) => guess.chars().count(),
+    None => return true,
+  };
+
+  let mut fixed: Vec<Option<char>> = vec![None; len];
+  let mut excluded: Vec<HashSet<char>> = vec![HashSet::new(); len];
+  let mut min_counts: HashMap<char, usize> = HashMap::new();
+  let mut max_counts: HashMap<char, usize> = HashMap::new();
+
+  for (guess, result) in rows {
+    let chars: Vec<char> = guess.chars().collect();
+    if chars.len() != len || result.len() != len {
+      return false;
+    }
+
+    let mut marked: HashMap<char, usize> = HashMap::new();
+    let mut grayed: HashSet<char> = HashSet::new();
+
+    for (i, (c, r)) in chars.iter().zip(result).enumerate() {
+      match r {
+        Correctness::Correct => {
+          if fixed[i].is_some_and(|f| f != *c) {
+            return false;
+          }
+          fixed[i] = Some(*c);
+          *marked.entry(*c).or_insert(0) += 1;
+        }
+        Correctness::IncorrectPlacement => {
+          excluded[i].insert(*c);
+          *marked.entry(*c).or_insert(0) += 1;
+        }
+        Correctness::Incorrect => {
+          excluded[i].insert(*c);
+          grayed.insert(*c);
+        }
+      }
+    }
+
+    for c in chars.iter() {
+      let count = marked.get(c).copied().unwrap_or(0);
+      let min = min_counts.entry(*c).or_insert(0);
+      *min = (*min).max(count);
+
+      if grayed.contains(c) {
+        let max = max_counts.entry(*c).or_insert(count);
+        *max = (*max).min(count);
+      }
+    }
+  }
+
+  for (i, f) in fixed.iter().enumerate() {
+    if let Some(c) = f {
+      if excluded[i].contains(c) {
+        return false;
+      }
+    }
+  }
+
+  if min_counts.iter().any(|(c, min)| *min > 0 && !alphabet.contains(c)) {
+    return false;
+  }
+
+  // Letters never mentioned in the grid are interchangeable, so one stands in for all
+  let mut letters: Vec<char> = min_counts.keys().copied().collect();
+  letters.sort_unstable();
+  if let Some(free) = alphabet.iter().copied().find(|c| !min_counts.contains_key(c)) {
+    letters.push(free);
+  }
+
+  let constraints = GridConstraints {
+    fixed,
+    excluded,
+    min_counts,
+    max_counts,
+    letters,
+  };
+
+  constraints.has_solution(0, &mut HashMap::new())
+}
+
+struct GridConstraints {
+  fixed: Vec<Option<char>>,
+  excluded: Vec<HashSet<char>>,
+  min_counts: HashMap<char, usize>,
+  max_counts: HashMap<char, usize>,
+  letters: Vec<char>,
+}
+
+impl GridConstraints {
+  fn has_solution(&self, pos: usize, counts: &mut HashMap<char, usize>) -> bool {
+    let missing: usize = self
+      .min_counts
+      .iter()
+      .map(|(c, min)| min.saturating_sub(counts.get(c).copied().unwrap_or(0)))
+      .sum();
+
+    if missing > self.fixed.len() - pos {
+      return false;
+    }
+    if pos == self.fixed.len() {
+      return true;
+    }
+
+    let options = match self.fixed[pos] {
+      Some(c) => vec![c],
+      None => self.letters.clone(),
+    };
+
+    for c in options {
+      let count = counts.get(&c).copied().unwrap_or(0);
+      let at_max = self.max_counts.get(&c).is_some_and(|max| count >= *max);
+      if self.excluded[pos].contains(&c) || at_max {
+        continue;
+      }
+
+      counts.insert(c, count + 1);
+      let found = self.has_solution(pos + 1, counts);
+      counts.insert(c, count);
+
+      if found {
+        return true;
+      }
+    }
+
+    false
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::{check_guess, english_alphabet};
+
+  fn row(guess: &str, answer: &str) -> (String, Vec<Correctness>) {
+    (String::from(guess), check_guess(guess, answer))
+  }
+
+  #[test]
+  fn it_should_pad_every_grid_row_to_the_same_width() {
+    let guesses = vec![
+      Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "those"),
+      },
+      Guess {
+        guess: String::from("sly"),
+        result: check_guess("sly", "tho"),
+      },
+      Guess {
+        guess: String::from("thoses"),
+        result: check_guess("thoses", "thoses"),
+      },
+    ];
+
+    let rows = get_formatted_grid(&guesses);
+
+    assert_eq!(rows.len(), 3);
+    for row in &rows {
+      assert_eq!(row.chars().count(), 6);
+      assert_eq!(display_width(row), display_width(&rows[2]));
+    }
+    assert!(rows[1].ends_with("⬛⬛⬛"));
+  }
+
+  #[test]
+  fn it_should_pad_short_rows_by_the_display_width_of_emoji_tiles() {
+    let guesses = vec![
+      Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "those"),
+      },
+      Guess {
+        guess: String::from("sly"),
+        result: check_guess("sly", "tho"),
+      },
+    ];
+
+    let rows = get_formatted_grid(&guesses);
+
+    assert_eq!(display_width(&rows[0]), 10);
+    assert_eq!(display_width(&rows[1]), 10);
+    assert!(rows[1].ends_with("⬛⬛"));
+    assert_eq!(rows[1].chars().count(), 5);
+  }
+
+  #[test]
+  fn it_should_cap_the_candidate_list_and_count_the_rest() {
+    let words = words(&["baker", "caker", "daker", "faker", "shirt"]);
+
+    assert_eq!(format_candidates_capped(&words, 2), "baker\ncaker\n... and 3 more");
+    assert_eq!(format_candidates_capped(&words, 0), "... and 5 more");
+    assert_eq!(format_candidates_capped(&words, 5), format_candidates(&words));
+  }
+
+  #[test]
+  fn it_should_format_one_scored_word_per_line() {
+    let scored = vec![(String::from("bcdfz"), 2.321928), (String::from("baker"), 0.5)];
+
+    assert_eq!(format_scored(&scored), "bcdfz 2.32\nbaker 0.50");
+  }
+
+  #[test]
+  fn it_should_color_each_letter_with_ansi_escapes() {
+    let guess = Guess {
+      guess: String::from("sit"),
+      result: vec![
+        Correctness::Correct,
+        Correctness::IncorrectPlacement,
+        Correctness::Incorrect,
+      ],
+    };
+
+    assert_eq!(
+      guess.render_colored(),
+      "\x1b[30;42ms\x1b[0m\x1b[30;43mi\x1b[0m\x1b[30;47mt\x1b[0m"
+    );
+  }
+
+  #[test]
+  fn it_should_wrap_the_grid_in_a_fenced_block() {
+    let mut wordle = Wordle::new(vec![String::from("salty"), String::from("shirt")]);
+    for word in ["salty", "shirt"] {
+      wordle
+        .add_guess(Guess {
+          guess: String::from(word),
+          result: check_guess(word, "shirt"),
+        })
+        .unwrap();
+    }
+
+    let markdown = wordle.to_markdown();
+    let lines: Vec<&str> = markdown.lines().collect();
+
+    assert_eq!(lines.first(), Some(&"```"));
+    assert_eq!(&lines[1..3], wordle.get_formatted_grid().as_slice());
+    assert_eq!(lines[3], "```");
+    assert_eq!(lines[4], "Solved in 2 guess(es)");
+  }
+
+  #[test]
+  fn it_should_list_one_candidate_per_line() {
+    let words = vec![String::from("shirt"), String::from("skirt")];
+
+    assert_eq!(format_candidates(&words), "shirt\nskirt");
+  }
+
+  #[test]
+  fn it_should_accept_a_grid_produced_by_a_real_answer() {
+    let rows = vec![row("geese", "those"), row("skirt", "those"), row("those", "those")];
+
+    assert!(is_consistent_grid(&rows, &english_alphabet()));
+  }
+
+  #[test]
+  fn it_should_only_fill_unknown_positions_from_the_alphabet() {
+    let rows = vec![(String::from("ab"), vec![Correctness::Incorrect; 2])];
+
+    assert!(is_consistent_grid(&rows, &english_alphabet()));
+    assert!(!is_consistent_grid(&rows, &['a', 'b']));
+    assert!(!is_consistent_grid(&[row("ab", "ab")], &['a', 'c']));
+  }
+
+  #[test]
+  fn it_should_reject_a_letter_that_is_green_then_gray_at_the_same_index() {
+    let rows = vec![
+      (
+        String::from("salty"),
+        vec![
+          Correctness::Correct,
+          Correctness::Incorrect,
+          Correctness::Incorrect,
+          Correctness::Incorrect,
+          Correctness::Incorrect,
+        ],
+      ),
+      (
+        String::from("shirt"),
+        vec![
+          Correctness::Incorrect,
+          Correctness::Incorrect,
+          Correctness::Incorrect,
+          Correctness::Incorrect,
+          Correctness::Incorrect,
+        ],
+      ),
+    ];
+
+    assert!(!is_consistent_grid(&rows, &english_alphabet()));
+  }
+
+  #[test]
+  fn it_should_reject_more_required_copies_than_a_gray_allows() {
+    let rows = vec![
+      (
+        String::from("eerie"),
+        vec![
+          Correctness::Correct,
+          Correctness::Correct,
+          Correctness::Incorrect,
+          Correctness::Incorrect,
+          Correctness::Incorrect,
+        ],
+      ),
+      (
+        String::from("geese"),
+        vec![
+          Correctness::Incorrect,
+          Correctness::Correct,
+          Correctness::IncorrectPlacement,
+          Correctness::Incorrect,
+          Correctness::IncorrectPlacement,
+        ],
+      ),
+    ];
+
+    assert!(!is_consistent_grid(&rows, &english_alphabet()));
+  }
+}
@@ -1,33 +1,27 @@
 use std::collections::HashSet;
 
+use super::Constraints;
+
+/// Whether `word` survives the given greens, yellows and grays. This is the only
+/// definition, kept for callers that track the letter lists themselves; it checks exactly
+/// what `Constraints::allows` does with those three lists.
 pub fn filter_dictionary(
-  word: &String,
-  incorrect_letters: &Vec<char>,
-  misplaced_letters: &Vec<char>,
-  correct_letters: &Vec<(char, u32)>,
+  word: &str,
+  incorrect_letters: impl AsRef<[char]>,
+  misplaced_letters: impl AsRef<[char]>,
+  correct_letters: impl AsRef<[(char, u32)]>,
 ) -> bool {
-  for c in incorrect_letters {
-    if word.contains(*c) {
-      return false;
-    }
-  }
-
-  for c in misplaced_letters {
-    if !word.contains(*c) {
-      return false;
-    }
-  }
-
-  for (c, i) in correct_letters {
-    if word.chars().nth(*i as usize).unwrap() != *c {
-      return false;
-    }
-  }
-
-  true
+  let constraints = Constraints {
+    incorrect_letters: incorrect_letters.as_ref().to_vec(),
+    correct_letters: correct_letters.as_ref().to_vec(),
+    misplaced_letters: misplaced_letters.as_ref().to_vec(),
+    ..Constraints::default()
+  };
+
+  constraints.allows(word)
 }
 
-pub fn has_double_letter(word: &String) -> bool {
+pub fn has_double_letter(word: &str) -> bool {
   let mut set = HashSet::new();
 
   for c in word.chars() {
@@ -38,9 +32,12 @@ pub fn has_double_letter(word: &String) -> bool {
     }
   }
 
-  return false;
+  false
 }
 
+#[cfg(test)]
+// These call filter_dictionary the way it was called when it took `&Vec`s
+#[allow(clippy::needless_borrows_for_generic_args)]
 mod tests {
   #[test]
   fn it_should_not_filter_the_word_if_no_incorrect_letters() {
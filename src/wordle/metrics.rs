@@ -0,0 +1,61 @@
+// Counters are thread-local so concurrent solves (and tests) don't see each other's work.
+// Without the `metrics` feature the record functions compile to nothing.
+
+#[cfg(feature = "metrics")]
+use std::cell::Cell;
+use std::time::Duration;
+
+#[cfg(feature = "metrics")]
+thread_local! {
+  static CHECK_GUESS_CALLS: Cell<usize> = const { Cell::new(0) };
+  static FILTER_EVALUATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Stats {
+  pub check_guess_calls: usize,
+  pub filter_evaluations: usize,
+  // Wall-clock time spent choosing each guess after the first, in order. Recorded with or
+  // without the `metrics` feature.
+  pub guess_timings: Vec<Duration>,
+}
+
+impl Stats {
+  pub fn total_choice_time(&self) -> Duration {
+    self.guess_timings.iter().sum()
+  }
+}
+
+#[inline]
+pub(crate) fn record_check_guess() {
+  #[cfg(feature = "metrics")]
+  CHECK_GUESS_CALLS.with(|c| c.set(c.get() + 1));
+}
+
+#[inline]
+pub(crate) fn record_filter_evaluation() {
+  #[cfg(feature = "metrics")]
+  FILTER_EVALUATIONS.with(|c| c.set(c.get() + 1));
+}
+
+pub(crate) fn reset() {
+  #[cfg(feature = "metrics")]
+  {
+    CHECK_GUESS_CALLS.with(|c| c.set(0));
+    FILTER_EVALUATIONS.with(|c| c.set(0));
+  }
+}
+
+pub(crate) fn snapshot() -> Stats {
+  #[cfg(feature = "metrics")]
+  {
+    Stats {
+      check_guess_calls: CHECK_GUESS_CALLS.with(|c| c.get()),
+      filter_evaluations: FILTER_EVALUATIONS.with(|c| c.get()),
+      guess_timings: Vec::new(),
+    }
+  }
+
+  #[cfg(not(feature = "metrics"))]
+  Stats::default()
+}
@@ -1,57 +1,342 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
 use rand::seq::SliceRandom;
 
 pub const GREEN_SQUARE: char = '🟩';
 pub const WHITE_SQUARE: char = '⬜';
 pub const YELLOW_SQUARE: char = '🟨';
 
+// Only allowed in words when punctuation is explicitly enabled
+pub const PUNCTUATION: [char; 2] = ['\'', '-'];
+
+mod absurdle;
+mod analysis;
+#[cfg(feature = "assist")]
+mod assist;
+mod candidates;
+mod config;
+mod constraints;
+mod encoding;
+mod error;
+mod feedback;
+mod files;
+mod grid;
 mod lib;
+mod metrics;
+mod multilength;
+mod query;
+mod simulate;
+mod solver;
+mod strategy;
+#[cfg(test)]
+mod test_util;
+
+pub use absurdle::AbsurdleHost;
+pub use analysis::{
+  coverage_report, is_vowel, letter_coverage, GuessGrade, HintLevel, ProgressReport,
+  StepAnalysis, StepResult,
+};
+#[cfg(feature = "assist")]
+pub use assist::{spawn_assist, NextSuggestion};
+pub use candidates::CandidateSet;
+pub use config::Config;
+pub use constraints::{derive_constraints, ConstraintKind, Constraints, Reason};
+pub use encoding::{parse_emoji, parse_gyx, to_emoji, to_gyx, GlyphSet, DEFAULT_GLYPHS};
+pub use error::{ConstraintConflict, GuessError, SolverError};
+pub use feedback::{FeedbackAlphabet, TileRule};
+pub use files::{
+  filter_file, grade_from_file, load_constraints, load_dictionary, merge_dictionaries,
+  parse_nyt_list, parse_nyt_lists, read_dictionary, read_lines, read_lines_from_file, replay_script,
+  MergedDictionaries, STDIN_PATH, WordList,
+};
+#[cfg(feature = "json")]
+pub use files::{load_baseline, save_baseline};
+pub use grid::{
+  display_width, format_candidates, format_candidates_capped, format_scored, get_formatted_grid,
+  is_consistent_grid, BLANK_CELL,
+};
+pub use lib::filter_dictionary;
+pub use metrics::Stats;
+pub use multilength::MultiLengthWordle;
+pub use query::{contains_letter, levenshtein, matches_pattern, query, words_within_edit_distance};
+pub use simulate::{
+  answers_solved_in, best_opener, best_opener_by_expected_guesses,
+  best_opener_by_expected_guesses_within, best_opener_pair, best_opener_pair_within,
+  compare_to_baseline, mean_guesses_over_answers, simulate, simulate_with, starter_recommendations,
+  worst_answer_for_opener, BaselineReport, ComputeBudget, SolveResult, SolveStats, UNSOLVED_GUESSES,
+};
+pub use solver::{estimate_difficulty, run_default_solve, solve, solve_lenient, Decision};
+pub use strategy::{
+  answer_probability, best_discriminator, best_guess, best_guess_by, best_guess_in,
+  best_guess_testing_letters, bigram_frequencies, bigram_score, choose_max_new_letters, entropy,
+  largest_partition, letter_frequency_score, minimal_separating_guesses, partition_by_guess, score,
+  score_all, score_in, GuessStrategy, RandomStrategy, Strategy,
+};
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct Wordle {
   pub guesses: Vec<Guess>,
   pub dictionary: Vec<String>,
-  pub incorrect_letters: Vec<char>,
-  pub correct_letters: Vec<(char, u32)>,
-  pub misplaced_letters: Vec<char>,
+  // The dictionary as loaded, before any guesses; shared between forked states
+  pub full_dictionary: Rc<Vec<String>>,
+  pub constraints: Constraints,
+  // Known before any guess, e.g. loaded with `load_constraints`; always part of `constraints`
+  pub applied_constraints: Constraints,
+  pub allow_punctuation: bool,
+  // Letters a word may be made of; anything else is rejected as a guess
+  pub alphabet: Vec<char>,
+  // Whether the vowel-based helpers count 'y' as a vowel; off to match common usage
+  pub treat_y_as_vowel: bool,
+  // Words never suggested as guesses, though they stay in the candidates and can still
+  // turn out to be the answer
+  pub blocklist: HashSet<String>,
+  // How common each word is, e.g. from a corpus count; higher means more likely to be the
+  // answer. Empty unless loaded
+  pub word_frequencies: HashMap<String, f64>,
+  // Record a `Decision` for every guess `solve` makes; off by default
+  pub log_decisions: bool,
+  decisions: Vec<Decision>,
+  // The word `step` applies feedback to: what it or `suggest` last showed the player
+  last_suggestion: Option<String>,
 }
 
 impl Wordle {
-  pub fn new(dictionary: Vec<String>) -> Wordle {
+  pub fn new(mut dictionary: Vec<String>) -> Wordle {
+    // An empty word can never be guessed or scored against
+    dictionary.retain(|word| !word.is_empty());
+
     Wordle {
       guesses: vec![],
-      dictionary: dictionary,
-      incorrect_letters: vec![],
-      correct_letters: vec![],
-      misplaced_letters: vec![],
+      full_dictionary: Rc::new(dictionary.clone()),
+      dictionary,
+      constraints: Constraints::default(),
+      applied_constraints: Constraints::default(),
+      allow_punctuation: false,
+      alphabet: english_alphabet(),
+      treat_y_as_vowel: false,
+      blocklist: HashSet::new(),
+      word_frequencies: HashMap::new(),
+      log_decisions: false,
+      decisions: vec![],
+      last_suggestion: None,
     }
   }
 
-  pub fn add_guess(&mut self, guess: Guess) {
-    self.guesses.push(guess);
+  /// Applies `guess` and narrows the candidates to words consistent with every guess so
+  /// far. Once the puzzle is solved, further guesses are ignored.
+  pub fn add_guess(&mut self, guess: Guess) -> Result<(), GuessError> {
+    if self.is_solved() {
+      return Ok(());
+    }
 
-    let g: &Guess = self.guesses.last().unwrap();
+    if !is_valid_word(&guess.guess, &self.alphabet, self.allow_punctuation) {
+      return Err(GuessError::InvalidCharacters(guess.guess));
+    }
+
+    for (i, (c, r)) in guess.annotated().into_iter().enumerate() {
+      if r != Correctness::Correct {
+        continue;
+      }
 
-    for (i, c) in g.guess.chars().enumerate() {
-      if matches!(g.result[i], Correctness::Correct) {
-        self.correct_letters.push((c, i.try_into().unwrap()));
-      } else if matches!(g.result[i], Correctness::IncorrectPlacement) {
-        self.misplaced_letters.push(c);
-      } else {
-        self.incorrect_letters.push(c)
+      let held = self.constraints.correct_letters.iter().find(|(_, j)| *j as usize == i);
+      if let Some((other, _)) = held.filter(|(other, _)| *other != c) {
+        return Err(GuessError::Inconsistent(ConstraintConflict::Position {
+          position: i,
+          letters: (*other, c),
+        }));
       }
     }
 
+    self.guesses.push(guess);
+    self.constraints = match self.rebuild_constraints() {
+      Ok(constraints) => constraints,
+      Err(conflict) => {
+        self.guesses.pop();
+        return Err(GuessError::Inconsistent(conflict));
+      }
+    };
+
+    let g: &Guess = self.guesses.last().unwrap();
+    let constraints = &self.constraints;
+
+    self.dictionary.retain(|word| {
+      metrics::record_filter_evaluation();
+      constraints.allows(word) && word != &g.guess
+    });
+
+    Ok(())
+  }
+
+  /// Narrows the candidates by constraints known from elsewhere, such as a resumed game,
+  /// and keeps them through later guesses and undos.
+  pub fn apply_constraints(&mut self, constraints: &Constraints) -> Result<(), GuessError> {
+    let mut applied = self.applied_constraints.clone();
+    applied.merge(constraints).map_err(GuessError::Inconsistent)?;
+    self.constraints.merge(constraints).map_err(GuessError::Inconsistent)?;
+    self.applied_constraints = applied;
+
+    let constraints = &self.constraints;
     self.dictionary.retain(|word| {
-      lib::filter_dictionary(
-        word,
-        &self.incorrect_letters,
-        &self.misplaced_letters,
-        &self.correct_letters,
-      ) && word != &g.guess
+      metrics::record_filter_evaluation();
+      constraints.allows(word)
     });
+
+    Ok(())
+  }
+
+  /// Drops `word` from both the candidates and the words that may be suggested, for
+  /// clones that reject a guess outright as not a valid word. Returns whether it was
+  /// known at all. Unlike the blocklist this is permanent, surviving undos.
+  pub fn reject_word(&mut self, word: &str) -> bool {
+    let known = self.full_dictionary.iter().any(|w| w == word);
+
+    self.dictionary.retain(|w| w != word);
+    if known {
+      Rc::make_mut(&mut self.full_dictionary).retain(|w| w != word);
+    }
+
+    known
+  }
+
+  fn rebuild_constraints(&self) -> Result<Constraints, ConstraintConflict> {
+    let mut constraints = derive_constraints(&self.guesses);
+    if self.applied_constraints != Constraints::default() {
+      constraints.merge(&self.applied_constraints)?;
+    }
+
+    Ok(constraints)
+  }
+
+  /// Why `word` is no longer a candidate, or `None` if it still is.
+  pub fn elimination_reason(&self, word: &str) -> Option<Reason> {
+    if self.dictionary.iter().any(|w| w == word) {
+      return None;
+    }
+
+    if let Some(reason) = self.constraints.violation(word) {
+      return Some(reason);
+    }
+
+    if self.guesses.iter().any(|g| g.guess == word) {
+      Some(Reason::AlreadyGuessed)
+    } else {
+      Some(Reason::NotInDictionary)
+    }
+  }
+
+  /// Takes back the most recent guess, rebuilding the constraints and candidates from
+  /// the guesses that remain.
+  pub fn undo_last_guess(&mut self) -> Option<Guess> {
+    let undone = self.guesses.pop()?;
+    // Every subset of guesses that was consistent with the applied constraints still is
+    self.constraints = self
+      .rebuild_constraints()
+      .expect("Constraints were consistent before the undo");
+
+    let guesses = &self.guesses;
+    let constraints = &self.constraints;
+    self.dictionary = self
+      .full_dictionary
+      .iter()
+      .filter(|word| constraints.allows(word) && !guesses.iter().any(|g| &g.guess == *word))
+      .cloned()
+      .collect();
+
+    Some(undone)
+  }
+
+  /// Every decision logged so far, oldest first. Undoing a guess doesn't remove its entry.
+  pub fn decision_log(&self) -> &[Decision] {
+    &self.decisions
+  }
+
+  /// Adds `decision` to the log if `log_decisions` is on.
+  pub fn record_decision(&mut self, decision: Decision) {
+    if self.log_decisions {
+      self.decisions.push(decision);
+    }
+  }
+
+  /// Forks the session so a hypothetical guess can be explored without touching this one.
+  pub fn clone_state(&self) -> Wordle {
+    self.clone()
+  }
+
+  pub fn best_guess(&self, strategy: Strategy) -> Option<&String> {
+    self.best_guess_by(strategy, |_, _| std::cmp::Ordering::Equal)
+  }
+
+  pub fn best_guess_by<F>(&self, strategy: Strategy, tie_break: F) -> Option<&String>
+  where
+    F: Fn(&str, &str) -> std::cmp::Ordering,
+  {
+    let (candidates, alphabet) = (&self.dictionary, &self.alphabet);
+    if self.blocklist.is_empty() {
+      return strategy::best_guess_in(candidates, candidates, strategy, alphabet, tie_break);
+    }
+
+    let allowed: Vec<String> = self
+      .dictionary
+      .iter()
+      .filter(|word| !self.blocklist.contains(*word))
+      .cloned()
+      .collect();
+    let choice = strategy::best_guess_in(candidates, &allowed, strategy, alphabet, tie_break)?;
+
+    self.dictionary.iter().find(|word| *word == choice)
+  }
+
+  /// The best of the player's own `shortlist`, scored against the current candidates.
+  /// The blocklist isn't applied, since the player chose these words.
+  pub fn best_among(&self, shortlist: &[String], strategy: Strategy) -> Option<String> {
+    strategy::best_guess(&self.dictionary, shortlist, strategy).cloned()
+  }
+
+  /// The allowed word trying the most letters not yet guessed, as `choose_max_new_letters`
+  /// picks it, skipping blocklisted words.
+  pub fn max_new_letters_guess(&self) -> Option<&String> {
+    let allowed: Vec<String> = self
+      .full_dictionary
+      .iter()
+      .filter(|word| !self.blocklist.contains(*word))
+      .cloned()
+      .collect();
+    if allowed.is_empty() {
+      return None;
+    }
+
+    let choice = strategy::choose_max_new_letters(&allowed, &self.tested_letters());
+    self.full_dictionary.iter().find(|word| *word == choice)
+  }
+
+  pub fn candidates_with(&self, predicate: impl Fn(&str) -> bool) -> Vec<&str> {
+    self
+      .dictionary
+      .iter()
+      .map(|word| word.as_str())
+      .filter(|word| predicate(word))
+      .collect()
+  }
+
+  /// The remaining candidates, if there are few enough to list instead of guessing again.
+  pub fn shortlist(&self, reveal_threshold: usize) -> Option<&[String]> {
+    if self.dictionary.is_empty() || self.dictionary.len() > reveal_threshold {
+      return None;
+    }
+
+    Some(&self.dictionary)
+  }
+
+  pub fn get_formatted_grid(&self) -> Vec<String> {
+    grid::get_formatted_grid(&self.guesses)
   }
 
   pub fn is_solved(&self) -> bool {
-    let last_guess = self.guesses.last().unwrap();
+    let last_guess = match self.guesses.last() {
+      Some(guess) => guess,
+      None => return false,
+    };
 
     for r in &last_guess.result {
       if !matches!(r, Correctness::Correct) {
@@ -63,76 +348,188 @@ impl Wordle {
   }
 }
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct Guess {
   pub guess: String,
   pub result: Vec<Correctness>,
 }
 
 impl Guess {
-  pub fn get_formatted_result(&self) -> String {
-    let mut result: String = String::new();
-
-    for r in &self.result {
-      if matches!(r, Correctness::Correct) {
-        result.push(GREEN_SQUARE);
-      } else if matches!(r, Correctness::IncorrectPlacement) {
-        result.push(YELLOW_SQUARE);
-      } else {
-        result.push(WHITE_SQUARE);
-      }
-    }
+  /// Each guessed letter alongside its feedback. If the two lengths disagree, the
+  /// unmatched tail of the longer one is dropped.
+  pub fn annotated(&self) -> Vec<(char, Correctness)> {
+    self.guess.chars().zip(self.result.iter().copied()).collect()
+  }
 
-    result
+  fn has_letter_marked(&self, letter: char) -> bool {
+    self
+      .annotated()
+      .iter()
+      .any(|(c, r)| *c == letter && !matches!(r, Correctness::Incorrect))
+  }
+
+  pub fn get_formatted_result(&self) -> String {
+    to_emoji(&self.result)
   }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Correctness {
   Correct,
   IncorrectPlacement,
   Incorrect,
 }
 
+pub fn english_alphabet() -> Vec<char> {
+  ('a'..='z').collect()
+}
+
+pub fn is_valid_word(word: &str, alphabet: &[char], allow_punctuation: bool) -> bool {
+  !word.is_empty()
+    && word
+      .chars()
+      .all(|c| alphabet.contains(&c) || (allow_punctuation && PUNCTUATION.contains(&c)))
+}
+
+pub fn validate_guess(guess: &str) -> Result<(), GuessError> {
+  if is_valid_word(guess, &english_alphabet(), false) {
+    Ok(())
+  } else {
+    Err(GuessError::InvalidCharacters(guess.to_string()))
+  }
+}
+
 // TODO: encapsulate this and make it private (?)
-pub fn check_guess(guess: &String, word: &String) -> Vec<Correctness> {
+pub fn check_guess(guess: &str, word: &str) -> Vec<Correctness> {
   let guess_chars: Vec<_> = guess.chars().collect();
   let word_chars: Vec<_> = word.chars().collect();
 
-  let mut correctness: Vec<Correctness> = Vec::new();
+  let mut correctness = Vec::new();
+  check_letters(&guess_chars, &word_chars, &mut Vec::new(), &mut correctness);
+  correctness
+}
+
+// `check_guess` for words already split into letters, overwriting `correctness`.
+// `unmatched` is scratch space, so callers checking many pairs can reuse both buffers.
+pub(crate) fn check_letters(
+  guess_chars: &[char],
+  word_chars: &[char],
+  unmatched: &mut Vec<char>,
+  correctness: &mut Vec<Correctness>,
+) {
+  metrics::record_check_guess();
+
+  // Letters of the word that weren't matched in place. Each one can mark at most one
+  // misplaced guess letter, so a repeated guess letter isn't reported more often than
+  // it actually occurs in the word.
+  unmatched.clear();
+  unmatched.extend(
+    word_chars
+      .iter()
+      .enumerate()
+      .filter(|(i, c)| guess_chars.get(*i) != Some(c))
+      .map(|(_, c)| *c),
+  );
+
+  correctness.clear();
 
-  for i in 0..guess_chars.len() {
-    if guess_chars[i] == word_chars[i] {
+  for (i, c) in guess_chars.iter().enumerate() {
+    if word_chars.get(i) == Some(c) {
       correctness.push(Correctness::Correct);
-    } else if word.contains(guess_chars[i]) {
-      // TODO: (maybe) need to account for case where letter is correctly placed elsewhere:
-      // e.g., double-letter word guesses
+    } else if let Some(pos) = unmatched.iter().position(|u| u == c) {
+      unmatched.swap_remove(pos);
       correctness.push(Correctness::IncorrectPlacement);
     } else {
       correctness.push(Correctness::Incorrect);
     }
   }
+}
 
-  correctness
+/// How `check_guess_lenient` lines up a guess and word of different lengths.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LengthPolicy {
+  /// Compare only the first `min(guess, word)` letters of each; the feedback is that long
+  Truncate,
+  /// Compare the whole guess, then mark any positions past its end gray; the feedback is as
+  /// long as the longer of the two
+  Pad,
 }
 
-pub fn choose_next_guess(dict: &Vec<String>) -> &String {
-  let mut num_choices = 0;
+pub fn check_guess_lenient(guess: &str, word: &str, policy: LengthPolicy) -> Vec<Correctness> {
+  match policy {
+    LengthPolicy::Truncate => {
+      let len = guess.chars().count().min(word.chars().count());
+      let guess: String = guess.chars().take(len).collect();
+      let word: String = word.chars().take(len).collect();
+
+      check_guess(&guess, &word)
+    }
+    LengthPolicy::Pad => {
+      let mut result = check_guess(guess, word);
+      let len = word.chars().count();
+      if result.len() < len {
+        result.resize(len, Correctness::Incorrect);
+      }
+
+      result
+    }
+  }
+}
 
-  loop {
-    let mut rng = rand::thread_rng();
-    let choice = dict.choose(&mut rng).unwrap();
+// Below this many candidates, words with double letters are as likely to be picked as
+// any other
+const AVOID_DOUBLES_FROM: usize = 10;
 
-    num_choices = num_choices + 1;
+/// A random word from `dict`, preferring ones without double letters when there are
+/// plenty to choose from. Panics if `dict` is empty.
+pub fn choose_next_guess(dict: &[String]) -> &String {
+  let mut rng = rand::thread_rng();
 
-    if dict.len() < 10 || !lib::has_double_letter(choice) || num_choices > 4 {
+  if dict.len() >= AVOID_DOUBLES_FROM {
+    let singles: Vec<&String> = dict.iter().filter(|w| !lib::has_double_letter(w)).collect();
+    if let Some(choice) = singles.choose(&mut rng) {
       return choice;
     }
   }
+
+  dict.choose(&mut rng).expect("No words to choose from")
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::wordle::test_util::words;
+
+  fn numbered(stem: &str, count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("{}{}", stem, i)).collect()
+  }
+
+  #[test]
+  fn it_should_avoid_double_letters_when_there_are_plenty_of_words() {
+    let mut dict = numbered("geese", 12);
+    dict.push(String::from("salty"));
+
+    for _ in 0..20 {
+      assert_eq!(choose_next_guess(&dict), "salty");
+    }
+  }
+
+  #[test]
+  fn it_should_not_avoid_double_letters_below_ten_words() {
+    let mut dict = numbered("geese", 8);
+    dict.push(String::from("salty"));
+
+    let picked_double = (0..200).any(|_| choose_next_guess(&dict) != "salty");
+
+    assert!(picked_double);
+  }
+
+  #[test]
+  fn it_should_fall_back_to_double_letters_when_every_word_has_them() {
+    let dict = numbered("geese", 12);
+
+    assert!(dict.contains(choose_next_guess(&dict)));
+  }
 
   #[test]
   fn it_should_return_all_correct() {
@@ -160,6 +557,268 @@ mod tests {
     assert!(matches!(result[4], Correctness::Correct));
   }
 
+  #[test]
+  fn it_should_not_mark_surplus_duplicate_letters_as_present() {
+    let result = check_guess("geese", "those");
+    assert_eq!(
+      result,
+      vec![
+        Correctness::Incorrect,
+        Correctness::Incorrect,
+        Correctness::Incorrect,
+        Correctness::Correct,
+        Correctness::Correct,
+      ]
+    );
+  }
+
+  #[test]
+  fn it_should_mark_only_as_many_misplaced_duplicates_as_the_word_has() {
+    let result = check_guess("speed", "abide");
+    assert_eq!(
+      result,
+      vec![
+        Correctness::Incorrect,
+        Correctness::Incorrect,
+        Correctness::IncorrectPlacement,
+        Correctness::Incorrect,
+        Correctness::IncorrectPlacement,
+      ]
+    );
+  }
+
+  #[test]
+  fn it_should_keep_the_answer_after_a_guess_with_a_surplus_duplicate() {
+    let mut wordle = Wordle::new(vec![String::from("those"), String::from("geode")]);
+    wordle.add_guess(Guess {
+      guess: String::from("geese"),
+      result: check_guess("geese", "those"),
+    })
+    .unwrap();
+
+    assert_eq!(wordle.dictionary, vec![String::from("those")]);
+  }
+
+  #[test]
+  fn it_should_reject_a_guess_with_non_alphabetic_characters() {
+    let mut wordle = Wordle::new(vec![String::from("salty")]);
+    let result = wordle.add_guess(Guess {
+      guess: String::from("sa1ty"),
+      result: check_guess("sa1ty", "salty"),
+    });
+
+    assert_eq!(result, Err(GuessError::InvalidCharacters(String::from("sa1ty"))));
+    assert!(wordle.guesses.is_empty());
+    assert_eq!(wordle.dictionary.len(), 1);
+  }
+
+  #[test]
+  fn it_should_reject_a_green_contradicting_an_earlier_green() {
+    let mut wordle = Wordle::new(vec![String::from("salty"), String::from("shirt")]);
+    wordle
+      .add_guess(Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "shirt"),
+      })
+      .unwrap();
+
+    let result = wordle.add_guess(Guess {
+      guess: String::from("those"),
+      result: vec![Correctness::Correct; 5],
+    });
+
+    assert_eq!(
+      result,
+      Err(GuessError::Inconsistent(ConstraintConflict::Position {
+        position: 0,
+        letters: ('s', 't')
+      }))
+    );
+    assert_eq!(wordle.guesses.len(), 1);
+  }
+
+  #[test]
+  fn it_should_ignore_guesses_once_solved() {
+    let mut wordle = Wordle::new(vec![String::from("salty"), String::from("shirt")]);
+    wordle
+      .add_guess(Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "salty"),
+      })
+      .unwrap();
+    let solved = wordle.clone();
+
+    wordle
+      .add_guess(Guess {
+        guess: String::from("shirt"),
+        result: check_guess("shirt", "salty"),
+      })
+      .unwrap();
+
+    assert_eq!(wordle, solved);
+    assert!(wordle.is_solved());
+  }
+
+  #[test]
+  fn it_should_reveal_the_shortlist_once_the_threshold_is_reached() {
+    let mut wordle = Wordle::new(words(&["salty", "shirt", "skirt", "lynch"]));
+    assert!(wordle.shortlist(2).is_none());
+
+    wordle
+      .add_guess(Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "shirt"),
+      })
+      .unwrap();
+
+    let shortlist = wordle.shortlist(2).unwrap();
+    assert_eq!(format_candidates(shortlist), "shirt\nskirt");
+    assert!(wordle.shortlist(0).is_none());
+  }
+
+  #[test]
+  fn it_should_leave_the_original_unchanged_when_a_fork_guesses() {
+    let wordle = Wordle::new(words(&["salty", "shirt", "skirt", "lynch"]));
+
+    let mut fork = wordle.clone_state();
+    fork
+      .add_guess(Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "shirt"),
+      })
+      .unwrap();
+
+    assert_eq!(fork.dictionary.len(), 2);
+    assert_eq!(wordle.dictionary.len(), 4);
+    assert!(wordle.guesses.is_empty());
+    assert!(Rc::ptr_eq(&wordle.full_dictionary, &fork.full_dictionary));
+  }
+
+  #[test]
+  fn it_should_clone_an_equal_wordle() {
+    let mut wordle = Wordle::new(vec![String::from("salty"), String::from("shirt")]);
+    wordle
+      .add_guess(Guess {
+        guess: String::from("lynch"),
+        result: check_guess("lynch", "shirt"),
+      })
+      .unwrap();
+
+    let clone = wordle.clone();
+
+    assert_eq!(clone.dictionary.len(), wordle.dictionary.len());
+    assert_eq!(clone, wordle);
+    assert!(format!("{:?}", clone.guesses[0]).contains("lynch"));
+  }
+
+  #[test]
+  fn it_should_accept_punctuation_in_a_guess_only_when_allowed() {
+    let mut wordle = Wordle::new(vec![String::from("don't")]);
+    let guess = Guess {
+      guess: String::from("don't"),
+      result: check_guess("don't", "don't"),
+    };
+
+    assert!(wordle.add_guess(guess.clone()).is_err());
+
+    wordle.allow_punctuation = true;
+    assert!(wordle.add_guess(guess).is_ok());
+    assert!(wordle.is_solved());
+  }
+
+  #[test]
+  fn it_should_never_keep_an_empty_word_in_the_dictionary() {
+    let wordle = Wordle::new(vec![String::from("salty"), String::new()]);
+
+    assert_eq!(wordle.dictionary, vec![String::from("salty")]);
+    assert!(validate_guess("").is_err());
+  }
+
+  #[test]
+  fn it_should_only_accept_guesses_from_a_custom_alphabet() {
+    let mut wordle = Wordle::new(vec![String::from("αβγ"), String::from("αγβ")]);
+    wordle.alphabet = vec!['α', 'β', 'γ'];
+
+    let rejected = wordle.add_guess(Guess {
+      guess: String::from("abc"),
+      result: check_guess("abc", "αβγ"),
+    });
+    assert_eq!(rejected, Err(GuessError::InvalidCharacters(String::from("abc"))));
+
+    wordle
+      .add_guess(Guess {
+        guess: String::from("αγβ"),
+        result: check_guess("αγβ", "αβγ"),
+      })
+      .unwrap();
+    assert_eq!(wordle.dictionary, vec![String::from("αβγ")]);
+  }
+
+  #[test]
+  fn it_should_pair_each_letter_with_its_feedback() {
+    let guess = Guess {
+      guess: String::from("skirt"),
+      result: check_guess("skirt", "shirt"),
+    };
+
+    assert_eq!(
+      guess.annotated(),
+      vec![
+        ('s', Correctness::Correct),
+        ('k', Correctness::Incorrect),
+        ('i', Correctness::Correct),
+        ('r', Correctness::Correct),
+        ('t', Correctness::Correct),
+      ]
+    );
+  }
+
+  #[test]
+  fn it_should_drop_unmatched_letters_when_annotating() {
+    let guess = Guess {
+      guess: String::from("skirts"),
+      result: check_guess("skirt", "shirt"),
+    };
+
+    assert_eq!(guess.annotated().len(), 5);
+  }
+
+  #[test]
+  fn it_should_truncate_mismatched_lengths_to_the_shorter_word() {
+    let result = check_guess_lenient("shirts", "shir", LengthPolicy::Truncate);
+    assert_eq!(result, vec![Correctness::Correct; 4]);
+
+    let result = check_guess_lenient("sale", "salty", LengthPolicy::Truncate);
+    assert_eq!(
+      result,
+      vec![
+        Correctness::Correct,
+        Correctness::Correct,
+        Correctness::Correct,
+        Correctness::Incorrect,
+      ]
+    );
+  }
+
+  #[test]
+  fn it_should_pad_mismatched_lengths_with_gray() {
+    let result = check_guess_lenient("salt", "salty", LengthPolicy::Pad);
+    assert_eq!(
+      result,
+      vec![
+        Correctness::Correct,
+        Correctness::Correct,
+        Correctness::Correct,
+        Correctness::Correct,
+        Correctness::Incorrect,
+      ]
+    );
+
+    let result = check_guess_lenient("saltyy", "salty", LengthPolicy::Pad);
+    assert_eq!(result.len(), 6);
+    assert_eq!(result[5], Correctness::Incorrect);
+  }
+
   #[test]
   fn it_should_render_a_correct_result_string() {
     let guess = Guess {
@@ -180,4 +839,112 @@ mod tests {
 
     assert_eq!(guess.get_formatted_result(), expected_result);
   }
+
+  #[test]
+  fn it_should_never_suggest_a_rejected_word_again() {
+    let mut wordle = Wordle::new(words(&["bcdfz", "baker", "caker", "daker", "faker"]));
+    let fork = wordle.clone_state();
+    assert_eq!(wordle.best_guess(Strategy::Entropy).unwrap(), "bcdfz");
+
+    assert!(wordle.reject_word("bcdfz"));
+    assert!(wordle.reject_word("baker"));
+
+    assert_ne!(wordle.best_guess(Strategy::Entropy).unwrap(), "bcdfz");
+    assert!(!wordle.dictionary.contains(&String::from("baker")));
+    assert!(!wordle.full_dictionary.contains(&String::from("baker")));
+    assert!(!wordle.reject_word("zzzzz"));
+    assert_eq!(fork.full_dictionary.len(), 5);
+  }
+
+  // A small alphabet so repeated letters, the case check_guess gets wrong most easily,
+  // come up often
+  fn word_pair() -> impl proptest::strategy::Strategy<Value = (String, String)> {
+    use proptest::prelude::*;
+
+    (1usize..=6).prop_flat_map(|len| {
+      let word = prop::collection::vec(prop::char::range('a', 'e'), len)
+        .prop_map(|chars| chars.into_iter().collect::<String>());
+      (word.clone(), word)
+    })
+  }
+
+  // An answer, some guesses at it in two different orders and a dictionary
+  type Game = (String, Vec<String>, Vec<String>, Vec<String>);
+
+  // Every word is the same length, as in a real game
+  fn same_length_game() -> impl proptest::strategy::Strategy<Value = Game> {
+    use proptest::prelude::*;
+
+    (1usize..=5).prop_flat_map(|len| {
+      let word = prop::collection::vec(prop::char::range('a', 'e'), len)
+        .prop_map(|chars| chars.into_iter().collect::<String>());
+      let guesses = prop::collection::vec(word.clone(), 1..=4);
+
+      (word.clone(), guesses, prop::collection::vec(word, 0..=20)).prop_flat_map(
+        |(answer, mut guesses, dictionary)| {
+          // Guessing the answer ends the game, so it can't come mid-way
+          guesses.retain(|guess| *guess != answer);
+          let shuffled = Just(guesses.clone()).prop_shuffle();
+          (Just(answer), Just(guesses), shuffled, Just(dictionary))
+        },
+      )
+    })
+  }
+
+  proptest::proptest! {
+    #[test]
+    fn it_should_mark_every_letter_green_against_itself((word, _) in word_pair()) {
+      let result = check_guess(&word, &word);
+      proptest::prop_assert!(result.iter().all(|r| *r == Correctness::Correct));
+    }
+
+    #[test]
+    fn it_should_never_mark_a_letter_more_often_than_the_answer_has_it(
+      (guess, answer) in word_pair()
+    ) {
+      let result = check_guess(&guess, &answer);
+
+      for c in guess.chars() {
+        let marked = guess
+          .chars()
+          .zip(&result)
+          .filter(|(g, r)| *g == c && **r != Correctness::Incorrect)
+          .count();
+        let available = answer.chars().filter(|a| *a == c).count();
+        proptest::prop_assert!(marked <= available);
+      }
+    }
+
+    #[test]
+    fn it_should_reach_the_same_state_whatever_order_the_guesses_come_in(
+      (answer, guesses, shuffled, dictionary) in same_length_game()
+    ) {
+      let play = |order: &[String]| {
+        let mut wordle = Wordle::new(dictionary.clone());
+        for guess in order {
+          wordle
+            .add_guess(Guess {
+              result: check_guess(guess, &answer),
+              guess: guess.clone(),
+            })
+            .unwrap();
+        }
+        wordle
+      };
+
+      let (first, second) = (play(&guesses), play(&shuffled));
+      proptest::prop_assert_eq!(&first.constraints, &second.constraints);
+      proptest::prop_assert_eq!(&first.dictionary, &second.dictionary);
+    }
+
+    #[test]
+    fn it_should_never_filter_out_the_true_answer((guess, answer) in word_pair()) {
+      let guess = Guess {
+        result: check_guess(&guess, &answer),
+        guess,
+      };
+
+      proptest::prop_assert!(derive_constraints(&[guess]).allows(&answer));
+    }
+  }
 }
@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use super::{is_valid_word, Guess, GuessError, Wordle};
+
+/// A game whose answer length isn't given up front. Candidates are split by length into
+/// one `Wordle` each, and a length is dropped once feedback rules out all of its words.
+#[derive(Debug, Clone)]
+pub struct MultiLengthWordle {
+  pub games: BTreeMap<usize, Wordle>,
+}
+
+impl MultiLengthWordle {
+  pub fn new(dictionary: Vec<String>) -> MultiLengthWordle {
+    let mut by_length: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+    for word in dictionary {
+      by_length.entry(word.chars().count()).or_default().push(word);
+    }
+
+    let games = by_length
+      .into_iter()
+      .map(|(len, words)| (len, Wordle::new(words)))
+      .filter(|(_, wordle)| !wordle.dictionary.is_empty())
+      .collect();
+
+    MultiLengthWordle { games }
+  }
+
+  /// Applies `guess` to every length still in play. Lengths the feedback contradicts, or
+  /// that have no candidates left without being solved, are dropped. Fails without
+  /// changing anything if the guess itself is invalid.
+  pub fn add_guess(&mut self, guess: Guess) -> Result<(), GuessError> {
+    if let Some(wordle) = self.games.values().next() {
+      if !is_valid_word(&guess.guess, &wordle.alphabet, wordle.allow_punctuation) {
+        return Err(GuessError::InvalidCharacters(guess.guess));
+      }
+    }
+
+    self.games.retain(|_, wordle| {
+      wordle.add_guess(guess.clone()).is_ok()
+        && (wordle.is_solved() || !wordle.dictionary.is_empty())
+    });
+
+    Ok(())
+  }
+
+  /// The answer lengths still possible, shortest first.
+  pub fn lengths(&self) -> Vec<usize> {
+    self.games.keys().copied().collect()
+  }
+
+  /// Candidates left across every length still in play.
+  pub fn candidate_count(&self) -> usize {
+    self.games.values().map(|wordle| wordle.dictionary.len()).sum()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::check_guess;
+
+  #[test]
+  fn it_should_drop_a_length_the_feedback_rules_out() {
+    let mut game = MultiLengthWordle::new(words(&["salt", "malt", "shirt", "lynch"]));
+    assert_eq!(game.lengths(), vec![4, 5]);
+    assert_eq!(game.candidate_count(), 4);
+
+    game
+      .add_guess(Guess {
+        guess: String::from("salty"),
+        result: check_guess("salty", "salt"),
+      })
+      .unwrap();
+
+    assert_eq!(game.lengths(), vec![4]);
+    assert_eq!(game.candidate_count(), 1);
+    assert_eq!(game.games[&4].dictionary, vec!["salt"]);
+  }
+
+  #[test]
+  fn it_should_reject_an_invalid_guess_without_dropping_any_length() {
+    let mut game = MultiLengthWordle::new(words(&["salt", "shirt"]));
+
+    let result = game.add_guess(Guess {
+      guess: String::from("sa1ty"),
+      result: check_guess("sa1ty", "salt"),
+    });
+
+    assert!(result.is_err());
+    assert_eq!(game.lengths(), vec![4, 5]);
+  }
+}
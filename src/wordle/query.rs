@@ -0,0 +1,138 @@
+use super::Constraints;
+
+pub fn contains_letter(letter: char) -> impl Fn(&str) -> bool {
+  move |word| word.contains(letter)
+}
+
+/// Matches words of the same length as `pattern`, where `_` stands for any letter.
+pub fn matches_pattern(pattern: &str) -> impl Fn(&str) -> bool {
+  let pattern: Vec<char> = pattern.chars().collect();
+
+  move |word| {
+    word.chars().count() == pattern.len()
+      && word
+        .chars()
+        .zip(&pattern)
+        .all(|(c, p)| *p == '_' || c == *p)
+  }
+}
+
+/// Crossword-style lookup: the words of `dict` that are `length` letters long, have each
+/// of `known_positions` in place and contain every letter of `must_contain` (a letter
+/// listed twice must appear at least twice).
+pub fn query(
+  dict: &[String],
+  length: usize,
+  known_positions: &[(char, u32)],
+  must_contain: &[char],
+) -> Vec<String> {
+  let mut constraints = Constraints {
+    correct_letters: known_positions.to_vec(),
+    ..Default::default()
+  };
+  for c in must_contain {
+    *constraints.min_counts.entry(*c).or_insert(0) += 1;
+  }
+
+  dict
+    .iter()
+    .filter(|word| word.chars().count() == length && constraints.allows(word))
+    .cloned()
+    .collect()
+}
+
+pub fn levenshtein(a: &str, b: &str) -> usize {
+  let b: Vec<char> = b.chars().collect();
+  let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+  for (i, ca) in a.chars().enumerate() {
+    let mut current = vec![i + 1];
+
+    for (j, cb) in b.iter().enumerate() {
+      let substitution = previous[j] + usize::from(ca != *cb);
+      current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+    }
+
+    previous = current;
+  }
+
+  previous[b.len()]
+}
+
+/// Candidates within `max` edits of `target`, closest first.
+pub fn words_within_edit_distance(
+  target: &str,
+  candidates: &[String],
+  max: usize,
+) -> Vec<(String, usize)> {
+  let mut near: Vec<(String, usize)> = candidates
+    .iter()
+    .map(|word| (word.clone(), levenshtein(target, word)))
+    .filter(|(_, distance)| *distance <= max)
+    .collect();
+
+  near.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+  near
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::Wordle;
+
+  fn wordle() -> Wordle {
+    Wordle::new(words(&["salty", "those", "shirt", "skirt", "lynch", "label"]))
+  }
+
+  #[test]
+  fn it_should_filter_candidates_by_letter() {
+    let wordle = wordle();
+
+    let candidates = wordle.candidates_with(contains_letter('l'));
+
+    assert_eq!(candidates.len(), 3);
+    assert_eq!(candidates, vec!["salty", "lynch", "label"]);
+  }
+
+  #[test]
+  fn it_should_measure_edit_distance() {
+    assert_eq!(levenshtein("shirt", "shirt"), 0);
+    assert_eq!(levenshtein("shirt", "skirt"), 1);
+    assert_eq!(levenshtein("shirt", "shirts"), 1);
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+  }
+
+  #[test]
+  fn it_should_return_neighbors_within_the_edit_distance() {
+    let candidates = words(&["skirt", "salty", "shire", "shirt", "lynch"]);
+
+    let near = words_within_edit_distance("shirt", &candidates, 1);
+
+    assert_eq!(
+      near,
+      vec![
+        (String::from("shirt"), 0),
+        (String::from("shire"), 1),
+        (String::from("skirt"), 1),
+      ]
+    );
+  }
+
+  #[test]
+  fn it_should_filter_candidates_by_pattern() {
+    let wordle = wordle();
+
+    assert_eq!(wordle.candidates_with(matches_pattern("s_ir_")), vec!["shirt", "skirt"]);
+    assert!(wordle.candidates_with(matches_pattern("s_ir")).is_empty());
+  }
+
+  #[test]
+  fn it_should_query_by_length_known_positions_and_required_letters() {
+    let dict = words(&["salty", "baker", "taker", "hat", "batty", "rates"]);
+
+    assert_eq!(query(&dict, 5, &[('a', 1)], &['t']), vec!["salty", "taker", "batty", "rates"]);
+    assert_eq!(query(&dict, 5, &[('a', 1)], &['t', 't']), vec!["batty"]);
+    assert_eq!(query(&dict, 3, &[], &['t']), vec!["hat"]);
+  }
+}
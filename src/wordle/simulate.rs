@@ -0,0 +1,830 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use super::{
+  best_guess, check_guess, english_alphabet, score_all, solve, Correctness, Guess, GuessStrategy,
+  Strategy, Wordle,
+};
+
+/// Limits on how much work an expensive search may do before settling for the best
+/// result found so far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComputeBudget {
+  /// How many nodes the search may evaluate; what a node is depends on the search
+  pub max_nodes: usize,
+  pub time_limit: Option<Duration>,
+}
+
+impl ComputeBudget {
+  pub fn unlimited() -> ComputeBudget {
+    ComputeBudget {
+      max_nodes: usize::MAX,
+      time_limit: None,
+    }
+  }
+
+  // At least one node is always allowed, so a search always has some result to return
+  fn allows(&self, nodes: usize, started: Instant) -> bool {
+    nodes == 0
+      || (nodes < self.max_nodes
+        && self.time_limit.is_none_or(|limit| started.elapsed() < limit))
+  }
+}
+
+impl Default for ComputeBudget {
+  fn default() -> ComputeBudget {
+    ComputeBudget::unlimited()
+  }
+}
+
+// A search's running count of nodes against its budget
+pub(crate) struct BudgetTracker {
+  budget: ComputeBudget,
+  started: Instant,
+  nodes: usize,
+  cut_short: bool,
+}
+
+impl BudgetTracker {
+  pub(crate) fn new(budget: ComputeBudget) -> BudgetTracker {
+    BudgetTracker {
+      budget,
+      started: Instant::now(),
+      nodes: 0,
+      cut_short: false,
+    }
+  }
+
+  // Uses up a node if the budget has one left, otherwise notes the search was cut short
+  pub(crate) fn take_node(&mut self) -> bool {
+    if !self.budget.allows(self.nodes, self.started) {
+      self.cut_short = true;
+      return false;
+    }
+
+    self.nodes += 1;
+    true
+  }
+
+  pub(crate) fn exhaustive(&self) -> bool {
+    !self.cut_short
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct SolveResult {
+  pub answer: String,
+  pub guesses_used: usize,
+  pub solved: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct SolveStats {
+  pub results: Vec<SolveResult>,
+}
+
+impl SolveStats {
+  pub fn mean_guesses(&self) -> f64 {
+    if self.results.is_empty() {
+      return 0.0;
+    }
+
+    let total: usize = self.results.iter().map(|r| r.guesses_used).sum();
+    total as f64 / self.results.len() as f64
+  }
+
+  /// The fraction of games solved within the guess limit.
+  pub fn win_rate(&self) -> f64 {
+    if self.results.is_empty() {
+      return 0.0;
+    }
+
+    let wins = self.results.iter().filter(|r| r.solved).count();
+    wins as f64 / self.results.len() as f64
+  }
+
+  /// Like `mean_guesses`, but only over the games that were solved.
+  pub fn average_guesses_on_wins(&self) -> f64 {
+    let wins: Vec<usize> = self
+      .results
+      .iter()
+      .filter(|r| r.solved)
+      .map(|r| r.guesses_used)
+      .collect();
+
+    if wins.is_empty() {
+      return 0.0;
+    }
+
+    wins.iter().sum::<usize>() as f64 / wins.len() as f64
+  }
+
+  pub fn to_csv(&self) -> String {
+    let mut csv = String::from("answer,guesses_used,solved\n");
+
+    for r in &self.results {
+      csv.push_str(&format!("{},{},{}\n", r.answer, r.guesses_used, r.solved));
+    }
+
+    csv
+  }
+}
+
+// How far mean guesses may rise, or the win rate fall, before it counts as a regression
+const REGRESSION_TOLERANCE: f64 = 0.01;
+
+/// How a run compares to a saved baseline, from `compare_to_baseline`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaselineReport {
+  /// Positive when the current run needs more guesses on average
+  pub mean_guesses_change: f64,
+  /// Negative when the current run wins fewer games
+  pub win_rate_change: f64,
+  pub regressed: bool,
+}
+
+/// Compares `current` to `baseline`, flagging a regression if mean guesses rose or the
+/// win rate fell by more than a small tolerance.
+pub fn compare_to_baseline(current: &SolveStats, baseline: &SolveStats) -> BaselineReport {
+  let mean_guesses_change = current.mean_guesses() - baseline.mean_guesses();
+  let win_rate_change = current.win_rate() - baseline.win_rate();
+
+  BaselineReport {
+    mean_guesses_change,
+    win_rate_change,
+    regressed: mean_guesses_change > REGRESSION_TOLERANCE
+      || win_rate_change < -REGRESSION_TOLERANCE,
+  }
+}
+
+/// Solves for every word in `answers`, opening with `opener` each time. A game only
+/// counts as solved if it finished within `max_guesses`.
+pub fn simulate(
+  answers: &[String],
+  opener: &str,
+  strategy: &dyn GuessStrategy,
+  max_guesses: usize,
+) -> SolveStats {
+  simulate_with(answers, opener, strategy, max_guesses, false)
+}
+
+/// Like `simulate`, but with `exclude_answer` set the answer is never guessed, to measure
+/// pure deduction: it's blocklisted, an opener equal to it is replaced by the strategy's
+/// own first choice, and a game is solved once the candidates collapse to the answer
+/// alone. A game also ends unsolved if the strategy repeats a guess or suggests the
+/// answer anyway.
+pub fn simulate_with(
+  answers: &[String],
+  opener: &str,
+  strategy: &dyn GuessStrategy,
+  max_guesses: usize,
+  exclude_answer: bool,
+) -> SolveStats {
+  let results = answers
+    .iter()
+    .map(|answer| play(answers, opener, answer, strategy, max_guesses, exclude_answer))
+    .collect();
+
+  SolveStats { results }
+}
+
+// One game of `simulate_with`
+fn play(
+  answers: &[String],
+  opener: &str,
+  answer: &str,
+  strategy: &dyn GuessStrategy,
+  max_guesses: usize,
+  exclude_answer: bool,
+) -> SolveResult {
+  let mut wordle = Wordle::new(answers.to_vec());
+  let solved = if exclude_answer {
+    deduce(&mut wordle, opener, answer, strategy)
+  } else {
+    solve(&mut wordle, opener, answer, strategy).is_ok() && wordle.is_solved()
+  };
+
+  SolveResult {
+    answer: answer.to_string(),
+    guesses_used: wordle.guesses.len(),
+    solved: solved && wordle.guesses.len() <= max_guesses,
+  }
+}
+
+// Guesses until only `answer` is left without ever guessing it, as `simulate_with`
+// describes. Whether the candidates collapsed.
+fn deduce(wordle: &mut Wordle, opener: &str, answer: &str, strategy: &dyn GuessStrategy) -> bool {
+  wordle.blocklist.insert(answer.to_string());
+
+  let mut next = if opener == answer {
+    strategy.choose(wordle)
+  } else {
+    Some(opener.to_string())
+  };
+
+  while wordle.dictionary.len() > 1 {
+    let word = match next {
+      Some(word) if word != answer && !wordle.guesses.iter().any(|g| g.guess == word) => word,
+      _ => break,
+    };
+
+    let guess = Guess {
+      result: check_guess(&word, answer),
+      guess: word,
+    };
+    if wordle.add_guess(guess).is_err() {
+      break;
+    }
+
+    next = strategy.choose(wordle);
+  }
+
+  wordle.dictionary == [answer]
+}
+
+/// What `mean_guesses_over_answers` counts a game the strategy can't finish as: one more
+/// guess than the six Wordle allows.
+pub const UNSOLVED_GUESSES: usize = 7;
+
+/// The answer that takes `strategy` the most guesses after opening with `opener`, and how
+/// many it took. Guesses may come from anywhere in `guesses`; ties go to the earlier
+/// answer. An answer the strategy never finds is the worst result of all, reported as
+/// `usize::MAX` guesses.
+pub fn worst_answer_for_opener(
+  opener: &str,
+  answers: &[String],
+  guesses: &[String],
+  strategy: &dyn GuessStrategy,
+) -> Option<(String, usize)> {
+  let mut worst: Option<(String, usize)> = None;
+
+  for answer in answers {
+    let used = guesses_to_solve(opener, answer, answers, guesses, strategy).unwrap_or(usize::MAX);
+
+    if worst.as_ref().is_none_or(|(_, most)| used > *most) {
+      worst = Some((answer.clone(), used));
+    }
+  }
+
+  worst
+}
+
+/// The mean number of guesses `strategy` takes over every word in `answers` after
+/// opening with `opener`, guessing from `guesses` like `worst_answer_for_opener`. A game
+/// the strategy can't finish counts as `UNSOLVED_GUESSES`.
+pub fn mean_guesses_over_answers(
+  strategy: &dyn GuessStrategy,
+  answers: &[String],
+  guesses: &[String],
+  opener: &str,
+) -> f64 {
+  if answers.is_empty() {
+    return 0.0;
+  }
+
+  let total: usize = answers
+    .iter()
+    .map(|answer| {
+      guesses_to_solve(opener, answer, answers, guesses, strategy).unwrap_or(UNSOLVED_GUESSES)
+    })
+    .sum();
+
+  total as f64 / answers.len() as f64
+}
+
+/// Every answer `strategy` finds in exactly `k` guesses after opening with `opener`,
+/// guessing from `guesses` like `worst_answer_for_opener`. Given only a solver's guess
+/// count for an unknown puzzle, these are the answers it could have been.
+pub fn answers_solved_in(
+  opener: &str,
+  k: usize,
+  answers: &[String],
+  guesses: &[String],
+  strategy: &dyn GuessStrategy,
+) -> Vec<String> {
+  answers
+    .iter()
+    .filter(|answer| guesses_to_solve(opener, answer, answers, guesses, strategy) == Some(k))
+    .cloned()
+    .collect()
+}
+
+// Guessing from the whole guess list but only counting answers as candidates. `None` if
+// the strategy couldn't find `answer`.
+fn guesses_to_solve(
+  opener: &str,
+  answer: &str,
+  answers: &[String],
+  guesses: &[String],
+  strategy: &dyn GuessStrategy,
+) -> Option<usize> {
+  let mut wordle = Wordle::new(guesses.to_vec());
+  wordle.dictionary = answers.to_vec();
+
+  if solve(&mut wordle, opener, answer, strategy).is_err() || !wordle.is_solved() {
+    return None;
+  }
+
+  Some(wordle.guesses.len())
+}
+
+// Keyed by a hash of the (answers, guesses) lists the opener was chosen for
+fn opener_cache() -> &'static Mutex<HashMap<u64, String>> {
+  static CACHE: OnceLock<Mutex<HashMap<u64, String>>> = OnceLock::new();
+  CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// Keyed by a hash of the dictionary
+fn entropy_opener_cache() -> &'static Mutex<HashMap<u64, String>> {
+  static CACHE: OnceLock<Mutex<HashMap<u64, String>>> = OnceLock::new();
+  CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// Keyed like `opener_cache`, plus the sample size
+fn opener_pair_cache() -> &'static Mutex<HashMap<u64, (String, String)>> {
+  static CACHE: OnceLock<Mutex<HashMap<u64, (String, String)>>> = OnceLock::new();
+  CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+type Ranking = Vec<(String, f64)>;
+
+// Keyed by a hash of the dictionary; every word's score, best first
+fn starter_cache() -> &'static Mutex<HashMap<u64, Ranking>> {
+  static CACHE: OnceLock<Mutex<HashMap<u64, Ranking>>> = OnceLock::new();
+  CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn cache_key<T: Hash>(value: T) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  value.hash(&mut hasher);
+  hasher.finish()
+}
+
+// The bundled word list's entropy-optimal opener, worked out ahead of time since scoring
+// the whole list takes several seconds even in a release build
+const BUNDLED_WORDS: &str = include_str!("../words.txt");
+const BUNDLED_OPENER: &str = "tares";
+
+fn is_bundled_list(dictionary: &[String]) -> bool {
+  dictionary.len() == BUNDLED_WORDS.lines().count()
+    && dictionary.iter().zip(BUNDLED_WORDS.lines()).all(|(a, b)| a == b)
+}
+
+/// The entropy-optimal first guess for `dictionary`, with every word both a possible
+/// answer and an allowed guess. Cached per dictionary, and precomputed for the bundled
+/// word list.
+pub fn best_opener(dictionary: &[String]) -> Option<String> {
+  if is_bundled_list(dictionary) {
+    return Some(BUNDLED_OPENER.to_string());
+  }
+
+  let key = cache_key(dictionary);
+  if let Some(opener) = entropy_opener_cache().lock().unwrap().get(&key) {
+    return Some(opener.clone());
+  }
+
+  let opener = best_guess(dictionary, dictionary, Strategy::Entropy)?.clone();
+  entropy_opener_cache().lock().unwrap().insert(key, opener.clone());
+  Some(opener)
+}
+
+/// The `n` best openers for `dictionary` by entropy, best first, with their scores in
+/// bits. Ties keep dictionary order. Every word is scored once per dictionary and
+/// cached, so asking for a different `n` is cheap.
+pub fn starter_recommendations(dictionary: &[String], n: usize) -> Vec<(String, f64)> {
+  let key = cache_key(dictionary);
+  if let Some(ranked) = starter_cache().lock().unwrap().get(&key) {
+    return ranked.iter().take(n).cloned().collect();
+  }
+
+  let mut ranked: Vec<(String, f64)> = dictionary
+    .iter()
+    .cloned()
+    .zip(score_all(Strategy::Entropy, dictionary, dictionary, &english_alphabet()))
+    .collect();
+  ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+  let top = ranked.iter().take(n).cloned().collect();
+  starter_cache().lock().unwrap().insert(key, ranked);
+  top
+}
+
+/// The opener from `guesses` with the lowest mean guess count when the entropy strategy
+/// plays out every answer after it. This runs a full simulation per opener, so results
+/// are cached for each answer/guess list pair.
+pub fn best_opener_by_expected_guesses(answers: &[String], guesses: &[String]) -> Option<String> {
+  best_opener_by_expected_guesses_within(answers, guesses, ComputeBudget::unlimited())
+    .map(|(opener, _)| opener)
+}
+
+/// Like `best_opener_by_expected_guesses`, but stops once `budget` runs out, with each
+/// game simulated counting as one node. Returns the best opener among those fully played
+/// out, or the first opener's partial mean if none was, and whether every opener was
+/// tried. Only exhaustive results are cached.
+pub fn best_opener_by_expected_guesses_within(
+  answers: &[String],
+  guesses: &[String],
+  budget: ComputeBudget,
+) -> Option<(String, bool)> {
+  let key = cache_key((answers, guesses));
+
+  if let Some(opener) = opener_cache().lock().unwrap().get(&key) {
+    return Some((opener.clone(), true));
+  }
+
+  let mut tracker = BudgetTracker::new(budget);
+  let mut best: Option<(&String, f64)> = None;
+  'openers: for opener in guesses {
+    let mut played = 0;
+    let mut total = 0;
+    for answer in answers {
+      if !tracker.take_node() {
+        // A partly played opener can't be compared fairly, so it only stands in for none
+        if best.is_none() && played > 0 {
+          best = Some((opener, total as f64 / played as f64));
+        }
+        break 'openers;
+      }
+
+      played += 1;
+      total += play(answers, opener, answer, &Strategy::Entropy, usize::MAX, false).guesses_used;
+    }
+
+    let mean = if played == 0 { 0.0 } else { total as f64 / played as f64 };
+    if best.is_none_or(|(_, best_mean)| mean < best_mean) {
+      best = Some((opener, mean));
+    }
+  }
+
+  let opener = best.map(|(opener, _)| opener.clone())?;
+  let exhaustive = tracker.exhaustive();
+  if exhaustive {
+    opener_cache().lock().unwrap().insert(key, opener.clone());
+  }
+  Some((opener, exhaustive))
+}
+
+/// The two openers from `guesses` that, played back to back before looking at any
+/// feedback, split `answers` into the most informative set of feedback pairs. Every pair
+/// is tried, so only `sample` answers spread evenly through the list are scored; pass
+/// `answers.len()` or more to use them all. Results are cached like
+/// `best_opener_by_expected_guesses`.
+pub fn best_opener_pair(
+  answers: &[String],
+  guesses: &[String],
+  sample: usize,
+) -> Option<(String, String)> {
+  best_opener_pair_within(answers, guesses, sample, ComputeBudget::unlimited())
+    .map(|(pair, _)| pair)
+}
+
+/// Like `best_opener_pair`, but stops once `budget` runs out, with each pair scored
+/// counting as one node. Returns the best pair among those tried and whether every pair
+/// was tried. Only exhaustive results are cached.
+pub fn best_opener_pair_within(
+  answers: &[String],
+  guesses: &[String],
+  sample: usize,
+  budget: ComputeBudget,
+) -> Option<((String, String), bool)> {
+  let key = cache_key((answers, guesses, sample));
+  if let Some(pair) = opener_pair_cache().lock().unwrap().get(&key) {
+    return Some((pair.clone(), true));
+  }
+
+  let step = answers.len().div_ceil(sample.max(1)).max(1);
+  let sampled: Vec<&String> = answers.iter().step_by(step).collect();
+
+  let mut tracker = BudgetTracker::new(budget);
+  let mut best: Option<((&String, &String), f64)> = None;
+  'pairs: for (i, first) in guesses.iter().enumerate() {
+    for second in &guesses[i + 1..] {
+      if !tracker.take_node() {
+        break 'pairs;
+      }
+
+      let info = pair_entropy(first, second, &sampled);
+      if best.is_none_or(|(_, best_info)| info > best_info) {
+        best = Some(((first, second), info));
+      }
+    }
+  }
+
+  let pair = best.map(|((first, second), _)| (first.clone(), second.clone()))?;
+  let exhaustive = tracker.exhaustive();
+  if exhaustive {
+    opener_pair_cache().lock().unwrap().insert(key, pair.clone());
+  }
+  Some((pair, exhaustive))
+}
+
+fn pair_entropy(first: &str, second: &str, answers: &[&String]) -> f64 {
+  let mut partitions: HashMap<(Vec<Correctness>, Vec<Correctness>), usize> = HashMap::new();
+  for answer in answers {
+    let feedback = (check_guess(first, answer), check_guess(second, answer));
+    *partitions.entry(feedback).or_insert(0) += 1;
+  }
+
+  let total = answers.len() as f64;
+  partitions
+    .values()
+    .map(|&size| {
+      let p = size as f64 / total;
+      -p * p.log2()
+    })
+    .sum()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::{load_dictionary, Strategy};
+  use std::collections::HashSet;
+  use std::path::Path;
+
+  #[test]
+  fn it_should_export_one_csv_row_per_answer() {
+    let answers = vec![String::from("salty")];
+
+    let stats = simulate(&answers, "salty", &Strategy::Entropy, 6);
+
+    assert_eq!(stats.to_csv(), "answer,guesses_used,solved\nsalty,1,true\n");
+  }
+
+  #[test]
+  fn it_should_only_count_wins_toward_the_win_rate_and_win_average() {
+    let result = |answer: &str, guesses_used: usize, solved: bool| SolveResult {
+      answer: String::from(answer),
+      guesses_used,
+      solved,
+    };
+    let stats = SolveStats {
+      results: vec![
+        result("salty", 2, true),
+        result("shirt", 4, true),
+        result("skirt", 7, false),
+        result("lynch", 3, true),
+      ],
+    };
+
+    assert!((stats.win_rate() - 0.75).abs() < 1e-9);
+    assert!((stats.average_guesses_on_wins() - 3.0).abs() < 1e-9);
+    assert_eq!(SolveStats::default().win_rate(), 0.0);
+    assert_eq!(SolveStats::default().average_guesses_on_wins(), 0.0);
+  }
+
+  #[test]
+  fn it_should_flag_a_run_that_needs_more_guesses_than_the_baseline() {
+    let result = |answer: &str, guesses_used: usize| SolveResult {
+      answer: String::from(answer),
+      guesses_used,
+      solved: true,
+    };
+    let baseline = SolveStats {
+      results: vec![result("salty", 2), result("shirt", 3)],
+    };
+    let worse = SolveStats {
+      results: vec![result("salty", 3), result("shirt", 4)],
+    };
+
+    let report = compare_to_baseline(&worse, &baseline);
+    assert!(report.regressed);
+    assert!((report.mean_guesses_change - 1.0).abs() < 1e-9);
+    assert_eq!(report.win_rate_change, 0.0);
+
+    assert!(!compare_to_baseline(&baseline, &worse).regressed);
+    assert!(!compare_to_baseline(&baseline, &baseline).regressed);
+  }
+
+  #[test]
+  fn it_should_use_the_precomputed_opener_for_the_bundled_word_list() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/words.txt");
+    let words = load_dictionary(&path, &english_alphabet(), false).unwrap().words;
+
+    assert!(is_bundled_list(&words));
+    assert_eq!(best_opener(&words), Some(String::from(BUNDLED_OPENER)));
+    assert!(!is_bundled_list(&words[1..]));
+  }
+
+  #[test]
+  fn it_should_pick_the_opener_with_the_lowest_mean_guesses() {
+    let answers = words(&["baker", "caker", "daker", "faker", "shirt"]);
+    let guesses = words(&["shirt", "bcdfz", "baker"]);
+
+    let brute_force = guesses
+      .iter()
+      .map(|g| (g, simulate(&answers, g, &Strategy::Entropy, usize::MAX).mean_guesses()))
+      .fold(None, |best: Option<(&String, f64)>, (g, mean)| match best {
+        Some((_, m)) if m <= mean => best,
+        _ => Some((g, mean)),
+      })
+      .unwrap();
+
+    let opener = best_opener_by_expected_guesses(&answers, &guesses).unwrap();
+
+    assert_eq!(&opener, brute_force.0);
+    assert_eq!(opener, "bcdfz");
+    assert_eq!(best_opener_by_expected_guesses(&answers, &guesses).unwrap(), opener);
+  }
+
+  #[test]
+  fn it_should_stop_early_when_the_budget_runs_out() {
+    let answers = words(&["baker", "caker", "daker", "faker", "shirt"]);
+    let guesses = words(&["shirt", "bcdfz", "baker", "caker"]);
+
+    let tight = ComputeBudget {
+      max_nodes: 1,
+      time_limit: Some(Duration::ZERO),
+    };
+    let (opener, exhaustive) =
+      best_opener_by_expected_guesses_within(&answers, &guesses, tight).unwrap();
+    assert_eq!(opener, "shirt");
+    assert!(!exhaustive);
+
+    let (opener, exhaustive) =
+      best_opener_by_expected_guesses_within(&answers, &guesses, ComputeBudget::default())
+        .unwrap();
+    assert_eq!(opener, "bcdfz");
+    assert!(exhaustive);
+    assert!(best_opener_by_expected_guesses_within(&answers, &[], tight).is_none());
+  }
+
+  #[test]
+  fn it_should_check_the_budget_between_games_not_just_between_openers() {
+    let answers = words(&["baker", "caker", "daker", "faker", "shirt"]);
+    // The second opener is better, but the budget runs out partway through its games
+    let guesses = words(&["shirt", "bcdfz"]);
+    let budget = ComputeBudget {
+      max_nodes: answers.len() + 2,
+      time_limit: None,
+    };
+
+    let (opener, exhaustive) =
+      best_opener_by_expected_guesses_within(&answers, &guesses, budget).unwrap();
+
+    assert_eq!(opener, "shirt");
+    assert!(!exhaustive);
+  }
+
+  #[test]
+  fn it_should_stop_scoring_opener_pairs_when_the_budget_runs_out() {
+    let answers = words(&["baker", "caker", "daker", "faker", "shirt"]);
+    let guesses = words(&["baker", "caker", "bcdfz", "shirt"]);
+    let tight = ComputeBudget {
+      max_nodes: 1,
+      time_limit: None,
+    };
+
+    let (pair, exhaustive) = best_opener_pair_within(&answers, &guesses, 5, tight).unwrap();
+    assert_eq!(pair, (String::from("baker"), String::from("caker")));
+    assert!(!exhaustive);
+
+    let (pair, exhaustive) =
+      best_opener_pair_within(&answers, &guesses, 5, ComputeBudget::default()).unwrap();
+    assert!(exhaustive);
+    assert_eq!(Some(pair), best_opener_pair(&answers, &guesses, 5));
+  }
+
+  #[test]
+  fn it_should_average_the_guesses_over_every_answer() {
+    let answers = words(&["baker", "caker", "daker", "faker", "shirt"]);
+    let mut guesses = answers.clone();
+    guesses.push(String::from("bcdfz"));
+
+    let brute_force: usize = answers
+      .iter()
+      .map(|answer| {
+        let mut wordle = Wordle::new(guesses.clone());
+        wordle.dictionary = answers.clone();
+        solve(&mut wordle, "shirt", answer, &Strategy::Entropy).unwrap();
+        wordle.guesses.len()
+      })
+      .sum();
+
+    let mean = mean_guesses_over_answers(&Strategy::Entropy, &answers, &guesses, "shirt");
+
+    assert!((mean - brute_force as f64 / answers.len() as f64).abs() < 1e-9);
+    assert_eq!(mean_guesses_over_answers(&Strategy::Entropy, &[], &guesses, "shirt"), 0.0);
+  }
+
+  #[test]
+  fn it_should_list_the_answers_solved_in_exactly_k_guesses() {
+    let answers = words(&["baker", "caker", "daker", "faker", "shirt"]);
+    let guesses = answers.clone();
+
+    let by_k: Vec<Vec<String>> = (1..=5)
+      .map(|k| answers_solved_in("shirt", k, &answers, &guesses, &Strategy::Entropy))
+      .collect();
+
+    // Each "_aker" guess only rules out itself, so they're found one guess apart
+    assert_eq!(by_k[0], vec!["shirt"]);
+    assert_eq!(by_k[4], vec!["faker"]);
+    assert_eq!(by_k.iter().filter(|set| set.contains(&String::from("faker"))).count(), 1);
+    assert_eq!(by_k.iter().map(|set| set.len()).sum::<usize>(), answers.len());
+  }
+
+  #[test]
+  fn it_should_need_more_guesses_when_the_answer_is_never_guessed() {
+    let answers = words(&["baker", "caker", "daker", "faker"]);
+
+    let direct = simulate_with(&answers, "baker", &Strategy::Entropy, 6, false);
+    let deduced = simulate_with(&answers, "baker", &Strategy::Entropy, 6, true);
+
+    assert_eq!(direct.results[0].guesses_used, 1);
+    assert!(deduced.results[0].guesses_used > 1);
+    assert!(deduced.results.iter().all(|r| r.solved));
+    assert_eq!(direct, simulate(&answers, "baker", &Strategy::Entropy, 6));
+  }
+
+  #[test]
+  fn it_should_recommend_starters_in_descending_order() {
+    let dictionary = words(&["baker", "caker", "daker", "faker", "shirt", "bcdfz"]);
+
+    let starters = starter_recommendations(&dictionary, 3);
+
+    assert_eq!(starters.len(), 3);
+    assert_eq!(starters[0].0, "bcdfz");
+    assert!(starters.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    assert_eq!(starter_recommendations(&dictionary, 10).len(), dictionary.len());
+    assert_eq!(starter_recommendations(&dictionary, 1)[..], starters[..1]);
+  }
+
+  #[test]
+  fn it_should_simulate_every_answer() {
+    let answers = words(&["salty", "shirt", "skirt", "lynch"]);
+
+    let stats = simulate(&answers, "salty", &Strategy::Entropy, 6);
+
+    assert_eq!(stats.results.len(), 4);
+    assert!(stats.results.iter().all(|r| r.solved));
+    assert_eq!(stats.to_csv().lines().count(), 5);
+  }
+
+  #[test]
+  fn it_should_pair_openers_covering_more_letters_than_either_alone() {
+    let answers = words(&["baker", "caker", "daker", "faker", "shirt", "skirt", "lynch"]);
+    let guesses = words(&["baker", "bcdfz", "shirt", "skirt"]);
+
+    let (first, second) = best_opener_pair(&answers, &guesses, answers.len()).unwrap();
+
+    let letters = |w: &str| w.chars().collect::<HashSet<char>>();
+    let combined: HashSet<char> = letters(&first).union(&letters(&second)).copied().collect();
+    assert_ne!(first, second);
+    assert!(combined.len() > letters(&first).len());
+    assert!(combined.len() > letters(&second).len());
+    assert_eq!(
+      best_opener_pair(&answers, &guesses, answers.len()),
+      Some((first, second))
+    );
+    assert!(best_opener_pair(&answers, &guesses[..1], 3).is_none());
+  }
+
+  #[test]
+  fn it_should_find_the_answer_an_opener_struggles_with() {
+    let answers = words(&["baker", "caker", "daker", "faker", "shirt"]);
+    let guesses = answers.clone();
+
+    let worst = worst_answer_for_opener("shirt", &answers, &guesses, &Strategy::Entropy);
+    assert_eq!(worst, Some((String::from("faker"), 5)));
+
+    assert!(worst_answer_for_opener("shirt", &[], &guesses, &Strategy::Entropy).is_none());
+  }
+
+  // Plays only the opener, then gives up
+  struct GiveUp;
+
+  impl GuessStrategy for GiveUp {
+    fn choose(&self, _: &Wordle) -> Option<String> {
+      None
+    }
+  }
+
+  #[test]
+  fn it_should_count_an_answer_never_found_as_the_worst() {
+    let answers = words(&["shirt", "baker", "caker"]);
+
+    let worst = worst_answer_for_opener("shirt", &answers, &answers, &GiveUp);
+    assert_eq!(worst, Some((String::from("baker"), usize::MAX)));
+
+    let worst = worst_answer_for_opener("shirt", &answers[..1], &answers, &GiveUp);
+    assert_eq!(worst, Some((String::from("shirt"), 1)));
+  }
+
+  #[test]
+  fn it_should_count_a_game_never_finished_as_a_failure_in_the_mean() {
+    let answers = words(&["shirt", "baker", "caker"]);
+
+    let mean = mean_guesses_over_answers(&GiveUp, &answers, &answers, "shirt");
+
+    assert!((mean - (1 + 2 * UNSOLVED_GUESSES) as f64 / 3.0).abs() < 1e-9);
+  }
+}
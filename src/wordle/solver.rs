@@ -0,0 +1,308 @@
+use std::path::Path;
+use std::time::Instant;
+
+use super::metrics::{self, Stats};
+use super::{
+  best_opener, check_guess, check_guess_lenient, english_alphabet, load_dictionary, Correctness,
+  Guess, GuessError, GuessStrategy, LengthPolicy, SolverError, Strategy, Wordle,
+};
+
+/// One step of a solve, as kept by `Wordle::decision_log`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decision {
+  /// Candidates left when the guess was chosen
+  pub candidates: usize,
+  pub guess: String,
+  /// The strategy's `name`, or "opener" for the caller's first guess
+  pub strategy: String,
+  pub result: Vec<Correctness>,
+}
+
+/// Plays `first_guess` and then keeps guessing with `strategy` until `target` is found or
+/// the strategy has nothing left to suggest. The guesses made are left in `wordle.guesses`.
+///
+/// The returned counters are only populated when built with the `metrics` feature, but the
+/// time spent choosing each guess is always recorded.
+pub fn solve(
+  wordle: &mut Wordle,
+  first_guess: &str,
+  target: &str,
+  strategy: &dyn GuessStrategy,
+) -> Result<Stats, GuessError> {
+  solve_scoring(wordle, first_guess, target, strategy, check_guess)
+}
+
+/// Like `solve`, but guesses needn't be as long as `target`: feedback is scored with
+/// `LengthPolicy::Pad`, so positions past the end of a short guess come back gray. Meant
+/// for deliberately wrong-length probes, usually the opener.
+pub fn solve_lenient(
+  wordle: &mut Wordle,
+  first_guess: &str,
+  target: &str,
+  strategy: &dyn GuessStrategy,
+) -> Result<Stats, GuessError> {
+  solve_scoring(wordle, first_guess, target, strategy, |guess, word| {
+    check_guess_lenient(guess, word, LengthPolicy::Pad)
+  })
+}
+
+fn solve_scoring(
+  wordle: &mut Wordle,
+  first_guess: &str,
+  target: &str,
+  strategy: &dyn GuessStrategy,
+  score: impl Fn(&str, &str) -> Vec<Correctness>,
+) -> Result<Stats, GuessError> {
+  metrics::reset();
+
+  let mut guess_timings = Vec::new();
+  let mut next_word = first_guess.to_string();
+  let mut chosen_by = String::from("opener");
+
+  loop {
+    let guess = Guess {
+      result: score(&next_word, target),
+      guess: next_word,
+    };
+    let decision = Decision {
+      candidates: wordle.dictionary.len(),
+      guess: guess.guess.clone(),
+      strategy: chosen_by.clone(),
+      result: guess.result.clone(),
+    };
+    wordle.add_guess(guess)?;
+    wordle.record_decision(decision);
+
+    if wordle.is_solved() || wordle.dictionary.is_empty() {
+      break;
+    }
+
+    let started = Instant::now();
+    let choice = strategy.choose(wordle);
+    guess_timings.push(started.elapsed());
+
+    next_word = match choice {
+      Some(word) => word,
+      None => break,
+    };
+    chosen_by = strategy.name();
+  }
+
+  Ok(Stats {
+    guess_timings,
+    ..metrics::snapshot()
+  })
+}
+
+/// How many guesses the entropy strategy needs to find `answer` among `candidates`, or
+/// `None` if it never finds it.
+pub fn estimate_difficulty(answer: &str, candidates: &[String]) -> Option<usize> {
+  let mut wordle = Wordle::new(candidates.to_vec());
+  let opener = Strategy::Entropy.choose(&wordle)?;
+
+  solve(&mut wordle, &opener, answer, &Strategy::Entropy).ok()?;
+  if !wordle.is_solved() {
+    return None;
+  }
+
+  Some(wordle.guesses.len())
+}
+
+/// Loads `wordfile`, opens with its entropy-optimal word and solves for `target` with the
+/// entropy strategy, returning the guesses made. The guesses stop short of `target` if it
+/// isn't in the word list. The bundled word list's opener is precomputed, so solving with
+/// it skips scoring every word up front.
+pub fn run_default_solve(wordfile: &str, target: &str) -> Result<Vec<Guess>, SolverError> {
+  let words = load_dictionary(Path::new(wordfile), &english_alphabet(), false)
+    .map_err(SolverError::Io)?
+    .words;
+  let opener = best_opener(&words)
+    .ok_or_else(|| SolverError::Parse(format!("No words to guess in {:?}", wordfile)))?;
+
+  let mut wordle = Wordle::new(words);
+  solve(&mut wordle, &opener, target, &Strategy::Entropy)?;
+
+  Ok(wordle.guesses)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::{RandomStrategy, Strategy};
+
+  fn dictionary() -> Vec<String> {
+    words(&["salty", "those", "shirt", "skirt", "lynch", "geese"])
+  }
+
+  #[test]
+  fn it_should_solve_for_a_word_in_the_dictionary() {
+    let mut wordle = Wordle::new(dictionary());
+    solve(&mut wordle, "salty", "skirt", &RandomStrategy).unwrap();
+
+    assert!(wordle.is_solved());
+    assert_eq!(wordle.guesses.last().unwrap().guess, "skirt");
+  }
+
+  struct Alphabetical;
+
+  impl GuessStrategy for Alphabetical {
+    fn choose(&self, wordle: &Wordle) -> Option<String> {
+      wordle.dictionary.iter().min().cloned()
+    }
+  }
+
+  #[test]
+  fn it_should_solve_with_a_custom_strategy() {
+    let mut wordle = Wordle::new(dictionary());
+    solve(&mut wordle, "lynch", "those", &Alphabetical).unwrap();
+
+    let guesses: Vec<_> = wordle.guesses.iter().map(|g| g.guess.as_str()).collect();
+    assert_eq!(guesses, vec!["lynch", "shirt", "those"]);
+    assert!(wordle.is_solved());
+  }
+
+  #[test]
+  fn it_should_solve_with_a_built_in_strategy() {
+    let mut wordle = Wordle::new(dictionary());
+    solve(&mut wordle, "salty", "shirt", &Strategy::Minimax).unwrap();
+
+    assert!(wordle.is_solved());
+  }
+
+  #[test]
+  fn it_should_take_one_guess_for_the_entropy_opener() {
+    let candidates = dictionary();
+    let opener = Strategy::Entropy.choose(&Wordle::new(candidates.clone())).unwrap();
+
+    assert_eq!(estimate_difficulty(&opener, &candidates), Some(1));
+    assert_eq!(estimate_difficulty("salty", &[]), None);
+    assert_eq!(estimate_difficulty("zzzzz", &candidates), None);
+  }
+
+  #[test]
+  fn it_should_self_play_a_short_opener_in_lenient_mode() {
+    let mut wordle = Wordle::new(dictionary());
+    solve_lenient(&mut wordle, "salt", "salty", &Strategy::Entropy).unwrap();
+
+    let opener = &wordle.guesses[0];
+    assert_eq!(opener.guess, "salt");
+    assert_eq!(opener.result.len(), 5);
+    assert_eq!(opener.result[4], Correctness::Incorrect);
+    assert!(wordle.is_solved());
+    assert_eq!(wordle.guesses.last().unwrap().guess, "salty");
+  }
+
+  #[test]
+  fn it_should_stop_on_an_invalid_first_guess() {
+    let mut wordle = Wordle::new(dictionary());
+
+    assert!(solve(&mut wordle, "sa1ty", "skirt", &RandomStrategy).is_err());
+  }
+
+  #[test]
+  fn it_should_log_one_decision_per_guess() {
+    let mut wordle = Wordle::new(dictionary());
+    wordle.log_decisions = true;
+    solve(&mut wordle, "lynch", "those", &Strategy::Minimax).unwrap();
+
+    let log = wordle.decision_log();
+
+    assert_eq!(log.len(), wordle.guesses.len());
+    assert_eq!(log[0].strategy, "opener");
+    assert_eq!(log[0].candidates, dictionary().len());
+    assert!(log[1..].iter().all(|d| d.strategy == "Minimax"));
+    for (decision, guess) in log.iter().zip(&wordle.guesses) {
+      assert_eq!(decision.guess, guess.guess);
+      assert_eq!(decision.result, guess.result);
+    }
+  }
+
+  #[test]
+  fn it_should_not_log_decisions_unless_asked() {
+    let mut wordle = Wordle::new(dictionary());
+    solve(&mut wordle, "lynch", "those", &Strategy::Minimax).unwrap();
+
+    assert!(wordle.decision_log().is_empty());
+  }
+
+  #[test]
+  fn it_should_time_every_guess_after_the_first() {
+    let mut wordle = Wordle::new(dictionary());
+    let stats = solve(&mut wordle, "lynch", "those", &Alphabetical).unwrap();
+
+    assert_eq!(stats.guess_timings.len(), wordle.guesses.len() - 1);
+    assert_eq!(stats.total_choice_time(), stats.guess_timings.iter().sum());
+  }
+
+  #[test]
+  fn it_should_solve_from_a_word_file_in_one_call() {
+    let path = std::env::temp_dir().join(format!("wordle_solver_{}_default", std::process::id()));
+    std::fs::write(&path, dictionary().join("\n")).unwrap();
+
+    let guesses = run_default_solve(path.to_str().unwrap(), "skirt");
+    std::fs::remove_file(&path).unwrap();
+
+    let guesses = guesses.unwrap();
+    assert_eq!(guesses.last().unwrap().guess, "skirt");
+    assert!(guesses.len() <= 6);
+    assert!(run_default_solve("/no/such/wordfile", "skirt").is_err());
+  }
+
+  #[test]
+  fn it_should_open_the_bundled_word_list_without_scoring_it() {
+    let wordfile = concat!(env!("CARGO_MANIFEST_DIR"), "/src/words.txt");
+
+    let guesses = run_default_solve(wordfile, "skirt").unwrap();
+
+    assert_eq!(guesses.first().unwrap().guess, "tares");
+    assert_eq!(guesses.last().unwrap().guess, "skirt");
+  }
+
+  // Pairs that tripped up check_guess before repeated letters were scored correctly
+  fn tricky_pairs() -> Vec<(String, String)> {
+    include_str!("fixtures/tricky_pairs.txt")
+      .lines()
+      .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+      .map(|line| {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        assert_eq!(words.len(), 2, "Malformed fixture line {:?}", line);
+        (words[0].to_string(), words[1].to_string())
+      })
+      .collect()
+  }
+
+  #[test]
+  fn it_should_solve_every_tricky_pair_within_six_guesses() {
+    let words: Vec<String> = include_str!("fixtures/tricky_words.txt")
+      .lines()
+      .map(|w| w.to_string())
+      .collect();
+    let pairs = tricky_pairs();
+    assert!(!pairs.is_empty());
+
+    for (opener, answer) in pairs {
+      let mut wordle = Wordle::new(words.clone());
+      solve(&mut wordle, &opener, &answer, &Strategy::Entropy).unwrap();
+
+      assert!(wordle.is_solved(), "{} didn't find {}", opener, answer);
+      assert!(
+        wordle.guesses.len() <= 6,
+        "{} took {} guesses to find {}",
+        opener,
+        wordle.guesses.len(),
+        answer
+      );
+    }
+  }
+
+  #[cfg(feature = "metrics")]
+  #[test]
+  fn it_should_count_solver_work_when_metrics_are_enabled() {
+    let mut wordle = Wordle::new(dictionary());
+    let stats = solve(&mut wordle, "salty", "skirt", &Strategy::Entropy).unwrap();
+
+    assert!(stats.check_guess_calls > 0);
+    assert!(stats.filter_evaluations > 0);
+  }
+}
@@ -0,0 +1,647 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{HashMap, HashSet};
+
+use super::{check_guess, check_letters, choose_next_guess, english_alphabet, Correctness, Wordle};
+
+// Scores closer than this are treated as a tie and handed to the tie-breaker
+const SCORE_EPSILON: f64 = 1e-9;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Strategy {
+  Entropy,
+  Minimax,
+  Frequency,
+  // How often the guess's adjacent letter pairs appear among the candidates
+  Bigram,
+  // Entropy while more than `switch_threshold` candidates remain, then the chance the guess
+  // is the answer
+  Balanced { switch_threshold: usize },
+  // Entropy minus `absent_penalty` for each letter of the guess no candidate contains,
+  // which includes every letter that's come back gray
+  PenalizedEntropy { absent_penalty: f64 },
+}
+
+pub trait GuessStrategy {
+  fn choose(&self, wordle: &Wordle) -> Option<String>;
+
+  /// How the strategy is identified in the decision log.
+  fn name(&self) -> String {
+    String::from("custom")
+  }
+}
+
+impl GuessStrategy for Strategy {
+  fn choose(&self, wordle: &Wordle) -> Option<String> {
+    wordle.best_guess(*self).cloned()
+  }
+
+  fn name(&self) -> String {
+    format!("{:?}", self)
+  }
+}
+
+/// Picks a random candidate, avoiding double letters while there are plenty to choose from
+/// and blocklisted words unless nothing else is left.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RandomStrategy;
+
+impl GuessStrategy for RandomStrategy {
+  fn choose(&self, wordle: &Wordle) -> Option<String> {
+    if wordle.dictionary.is_empty() {
+      return None;
+    }
+
+    let open: Vec<String> = wordle
+      .dictionary
+      .iter()
+      .filter(|word| !wordle.blocklist.contains(*word))
+      .cloned()
+      .collect();
+
+    if open.is_empty() {
+      return Some(choose_next_guess(&wordle.dictionary).clone());
+    }
+
+    Some(choose_next_guess(&open).clone())
+  }
+
+  fn name(&self) -> String {
+    String::from("Random")
+  }
+}
+
+/// Groups `candidates` by the feedback `guess` would receive if each were the answer.
+pub fn partition_by_guess(guess: &str, candidates: &[String]) -> HashMap<Vec<Correctness>, usize> {
+  let mut partitions = HashMap::new();
+
+  for candidate in candidates {
+    *partitions.entry(check_guess(guess, candidate)).or_insert(0) += 1;
+  }
+
+  partitions
+}
+
+pub fn entropy(guess: &str, candidates: &[String]) -> f64 {
+  partition_entropy(&partition_by_guess(guess, candidates), candidates.len())
+}
+
+fn partition_entropy(partitions: &HashMap<Vec<Correctness>, usize>, total: usize) -> f64 {
+  let total = total as f64;
+
+  partitions
+    .values()
+    .map(|&size| {
+      let p = size as f64 / total;
+      -p * p.log2()
+    })
+    .sum()
+}
+
+// `entropy` for every word of `allowed`. Scoring a whole list against itself for the
+// opening guess checks every pair, so each word is split into letters once up front and
+// the feedback buffers are reused, leaving one allocation per distinct pattern.
+fn entropy_all(allowed: &[String], candidates: &[String]) -> Vec<f64> {
+  let split = |words: &[String]| -> Vec<Vec<char>> {
+    words.iter().map(|w| w.chars().collect()).collect()
+  };
+  let candidate_letters = split(candidates);
+
+  let entropy_of = |(unmatched, feedback): &mut (Vec<char>, Vec<Correctness>), guess: &Vec<char>| {
+    let mut partitions: HashMap<Vec<Correctness>, usize> = HashMap::new();
+    for candidate in &candidate_letters {
+      check_letters(guess, candidate, unmatched, feedback);
+      match partitions.get_mut(feedback.as_slice()) {
+        Some(size) => *size += 1,
+        None => {
+          partitions.insert(feedback.clone(), 1);
+        }
+      }
+    }
+
+    partition_entropy(&partitions, candidates.len())
+  };
+
+  #[cfg(feature = "parallel")]
+  {
+    use rayon::prelude::*;
+
+    split(allowed)
+      .par_iter()
+      .map_init(|| (Vec::new(), Vec::new()), entropy_of)
+      .collect()
+  }
+
+  #[cfg(not(feature = "parallel"))]
+  {
+    let mut buffers = (Vec::new(), Vec::new());
+    split(allowed)
+      .iter()
+      .map(|guess| entropy_of(&mut buffers, guess))
+      .collect()
+  }
+}
+
+pub fn largest_partition(guess: &str, candidates: &[String]) -> usize {
+  partition_by_guess(guess, candidates)
+    .values()
+    .copied()
+    .max()
+    .unwrap_or(0)
+}
+
+/// How many candidates contain each distinct letter of `guess`, summed. Characters
+/// outside `alphabet`, such as punctuation, don't score.
+pub fn letter_frequency_score(guess: &str, candidates: &[String], alphabet: &[char]) -> f64 {
+  let mut seen = Vec::new();
+  let mut score = 0;
+
+  for c in guess.chars() {
+    if seen.contains(&c) || !alphabet.contains(&c) {
+      continue;
+    }
+    seen.push(c);
+    score += candidates.iter().filter(|w| w.contains(c)).count();
+  }
+
+  score as f64
+}
+
+/// How many times each pair of adjacent letters appears across `words`.
+pub fn bigram_frequencies(words: &[String]) -> HashMap<(char, char), usize> {
+  let mut frequencies = HashMap::new();
+
+  for word in words {
+    let chars: Vec<char> = word.chars().collect();
+    for pair in chars.windows(2) {
+      *frequencies.entry((pair[0], pair[1])).or_insert(0) += 1;
+    }
+  }
+
+  frequencies
+}
+
+/// The candidate frequencies of each distinct bigram in `guess`, summed, so guesses
+/// covering the commonest remaining letter pairs score highest.
+pub fn bigram_score(guess: &str, candidates: &[String]) -> f64 {
+  bigram_score_with(guess, &bigram_frequencies(candidates))
+}
+
+// `bigram_score` against frequencies already counted, so a pass over many guesses counts once
+fn bigram_score_with(guess: &str, frequencies: &HashMap<(char, char), usize>) -> f64 {
+  let chars: Vec<char> = guess.chars().collect();
+  let pairs: HashSet<(char, char)> = chars.windows(2).map(|pair| (pair[0], pair[1])).collect();
+
+  pairs.iter().map(|pair| frequencies.get(pair).copied().unwrap_or(0)).sum::<usize>() as f64
+}
+
+/// The chance `guess` is the answer, with every candidate equally likely.
+pub fn answer_probability(guess: &str, candidates: &[String]) -> f64 {
+  if !candidates.iter().any(|c| c == guess) {
+    return 0.0;
+  }
+
+  1.0 / candidates.len() as f64
+}
+
+/// Higher is better for every strategy. Letters are scored as in the English alphabet.
+pub fn score(strategy: Strategy, guess: &str, candidates: &[String]) -> f64 {
+  score_in(strategy, guess, candidates, &english_alphabet())
+}
+
+/// Like `score`, but only letters of `alphabet` count towards letter frequencies.
+pub fn score_in(strategy: Strategy, guess: &str, candidates: &[String], alphabet: &[char]) -> f64 {
+  match strategy {
+    Strategy::Entropy => entropy(guess, candidates),
+    Strategy::Minimax => -(largest_partition(guess, candidates) as f64),
+    Strategy::Frequency => letter_frequency_score(guess, candidates, alphabet),
+    Strategy::Bigram => bigram_score(guess, candidates),
+    Strategy::Balanced { switch_threshold } => {
+      if candidates.len() > switch_threshold {
+        entropy(guess, candidates)
+      } else {
+        answer_probability(guess, candidates)
+      }
+    }
+    Strategy::PenalizedEntropy { absent_penalty } => {
+      let absent = guess
+        .chars()
+        .filter(|c| !candidates.iter().any(|w| w.contains(*c)))
+        .count();
+
+      entropy(guess, candidates) - absent_penalty * absent as f64
+    }
+  }
+}
+
+/// `score_in` for every word of `allowed`, in order. With the `parallel` feature the words
+/// are scored across threads, which gives the same scores but leaves the metrics
+/// counters on the calling thread short of the work done. Entropy, the opening
+/// strategy, takes a faster path that splits every word into letters just once, and
+/// Bigram counts the candidates' letter pairs once for the whole pass.
+pub fn score_all(
+  strategy: Strategy,
+  allowed: &[String],
+  candidates: &[String],
+  alphabet: &[char],
+) -> Vec<f64> {
+  if strategy == Strategy::Entropy {
+    return entropy_all(allowed, candidates);
+  }
+  if strategy == Strategy::Bigram {
+    let frequencies = bigram_frequencies(candidates);
+    return allowed.iter().map(|word| bigram_score_with(word, &frequencies)).collect();
+  }
+
+  #[cfg(feature = "parallel")]
+  {
+    use rayon::prelude::*;
+
+    allowed
+      .par_iter()
+      .map(|word| score_in(strategy, word, candidates, alphabet))
+      .collect()
+  }
+
+  #[cfg(not(feature = "parallel"))]
+  allowed
+    .iter()
+    .map(|word| score_in(strategy, word, candidates, alphabet))
+    .collect()
+}
+
+pub fn best_guess<'a>(
+  candidates: &[String],
+  allowed: &'a [String],
+  strategy: Strategy,
+) -> Option<&'a String> {
+  best_guess_by(candidates, allowed, strategy, |_, _| Ordering::Equal)
+}
+
+/// Like `best_guess`, but words with equal scores are ordered by `tie_break` and the
+/// smallest is chosen. Ties that the comparator also considers equal keep `allowed` order.
+pub fn best_guess_by<'a, F>(
+  candidates: &[String],
+  allowed: &'a [String],
+  strategy: Strategy,
+  tie_break: F,
+) -> Option<&'a String>
+where
+  F: Fn(&str, &str) -> Ordering,
+{
+  best_guess_in(candidates, allowed, strategy, &english_alphabet(), tie_break)
+}
+
+/// Like `best_guess_by`, for words made of `alphabet` instead of English letters.
+pub fn best_guess_in<'a, F>(
+  candidates: &[String],
+  allowed: &'a [String],
+  strategy: Strategy,
+  alphabet: &[char],
+  tie_break: F,
+) -> Option<&'a String>
+where
+  F: Fn(&str, &str) -> Ordering,
+{
+  let scored: Vec<(&String, f64)> = allowed
+    .iter()
+    .zip(score_all(strategy, allowed, candidates, alphabet))
+    .collect();
+
+  let top = scored
+    .iter()
+    .map(|(_, s)| *s)
+    .fold(f64::NEG_INFINITY, f64::max);
+
+  scored
+    .into_iter()
+    .filter(|(_, s)| top - s < SCORE_EPSILON)
+    .map(|(word, _)| word)
+    .min_by(|a, b| tie_break(a, b))
+}
+
+/// The allowed word that splits `candidates` into the most distinct feedback patterns,
+/// preferring smaller worst cases and then earlier words among ties.
+pub fn best_discriminator<'a>(candidates: &[String], allowed: &'a [String]) -> Option<&'a String> {
+  // Reversed for the same reason as in best_guess_testing_letters
+  allowed.iter().rev().max_by_key(|word| {
+    let partitions = partition_by_guess(word, candidates);
+    let largest = partitions.values().copied().max().unwrap_or(0);
+    (partitions.len(), Reverse(largest))
+  })
+}
+
+/// A small set of `guesses` whose combined feedback tells every word of `answers` apart,
+/// built greedily by adding whichever guess splits the answers into the most groups.
+/// `None` if no such set of at most `max_set` guesses is found.
+pub fn minimal_separating_guesses(
+  answers: &[String],
+  guesses: &[String],
+  max_set: usize,
+) -> Option<Vec<String>> {
+  // Answers with the same group id have had identical feedback from every chosen guess
+  let mut groups: Vec<usize> = vec![0; answers.len()];
+  let mut chosen: Vec<String> = Vec::new();
+
+  loop {
+    let group_count = groups.iter().collect::<HashSet<_>>().len();
+    if group_count == answers.len() {
+      return Some(chosen);
+    }
+    if chosen.len() >= max_set {
+      return None;
+    }
+
+    let split = |guess: &String| -> Vec<(usize, Vec<Correctness>)> {
+      answers
+        .iter()
+        .zip(&groups)
+        .map(|(answer, group)| (*group, check_guess(guess, answer)))
+        .collect()
+    };
+
+    // Reversed for the same reason as in best_guess_testing_letters
+    let (guess, keys) = guesses
+      .iter()
+      .rev()
+      .map(|guess| (guess, split(guess)))
+      .max_by_key(|(_, keys)| keys.iter().collect::<HashSet<_>>().len())?;
+
+    let mut ids: HashMap<&(usize, Vec<Correctness>), usize> = HashMap::new();
+    for key in &keys {
+      let next = ids.len();
+      ids.entry(key).or_insert(next);
+    }
+    if ids.len() == group_count {
+      return None;
+    }
+
+    groups = keys.iter().map(|key| ids[key]).collect();
+    chosen.push(guess.clone());
+  }
+}
+
+/// Picks the allowed word containing the most distinct letters from `letters`, to
+/// confirm or rule them out in one guess. Panics if `allowed` is empty.
+pub fn best_guess_testing_letters<'a>(letters: &[char], allowed: &'a [String]) -> &'a String {
+  // max_by_key keeps the last of equal maximums, so reverse to prefer earlier words
+  allowed
+    .iter()
+    .rev()
+    .max_by_key(|word| letters.iter().filter(|c| word.contains(**c)).count())
+    .expect("No allowed words to choose from")
+}
+
+/// Picks the allowed word with the most distinct letters not in `already_tested`, for
+/// players who just want to try new letters. Ties go to the earlier word. Panics if
+/// `allowed` is empty.
+pub fn choose_max_new_letters<'a>(
+  allowed: &'a [String],
+  already_tested: &HashSet<char>,
+) -> &'a String {
+  // Reversed for the same reason as in best_guess_testing_letters
+  allowed
+    .iter()
+    .rev()
+    .max_by_key(|word| {
+      let new: HashSet<char> = word.chars().filter(|c| !already_tested.contains(c)).collect();
+      new.len()
+    })
+    .expect("No allowed words to choose from")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::wordle::test_util::words;
+  use crate::wordle::Guess;
+
+  #[test]
+  fn it_should_prefer_the_higher_entropy_guess() {
+    let candidates = words(&["baker", "caker", "daker", "faker"]);
+    let allowed = words(&["baker", "bcdfz"]);
+
+    let choice = best_guess(&candidates, &allowed, Strategy::Entropy);
+
+    assert_eq!(choice.unwrap(), "bcdfz");
+  }
+
+  #[test]
+  fn it_should_switch_from_probing_to_candidates_at_the_threshold() {
+    let candidates = words(&["baker", "caker", "daker", "faker"]);
+    let allowed = words(&["bcdfz", "baker", "caker", "daker", "faker"]);
+
+    let early = best_guess(&candidates, &allowed, Strategy::Balanced { switch_threshold: 3 });
+    let late = best_guess(&candidates, &allowed, Strategy::Balanced { switch_threshold: 4 });
+
+    assert_eq!(early.unwrap(), "bcdfz");
+    assert!(candidates.contains(late.unwrap()));
+  }
+
+  #[test]
+  fn it_should_leave_ties_between_a_candidate_and_a_probe_to_the_comparator() {
+    let candidates = words(&["baker"]);
+    let allowed = words(&["shirt", "baker"]);
+
+    assert_eq!(best_guess(&candidates, &allowed, Strategy::Entropy).unwrap(), "shirt");
+    let balanced = Strategy::Balanced { switch_threshold: 1 };
+    assert_eq!(best_guess(&candidates, &allowed, balanced).unwrap(), "baker");
+  }
+
+  #[test]
+  fn it_should_let_an_absent_letter_penalty_reorder_tied_guesses() {
+    let candidates = words(&["baker", "caker", "daker", "faker"]);
+    let allowed = words(&["bcdfz", "bcdfa"]);
+
+    let plain = best_guess(&candidates, &allowed, Strategy::Entropy);
+    let penalized = best_guess(
+      &candidates,
+      &allowed,
+      Strategy::PenalizedEntropy { absent_penalty: 0.01 },
+    );
+
+    assert_eq!(plain.unwrap(), "bcdfz");
+    assert_eq!(penalized.unwrap(), "bcdfa");
+  }
+
+  #[test]
+  fn it_should_keep_allowed_order_for_ties_by_default() {
+    let candidates = words(&["stare", "tears", "rates"]);
+    let allowed = words(&["stare", "tears"]);
+
+    let choice = best_guess(&candidates, &allowed, Strategy::Frequency);
+
+    assert_eq!(choice.unwrap(), "stare");
+  }
+
+  #[test]
+  fn it_should_let_a_custom_comparator_break_ties() {
+    let candidates = words(&["stare", "tears", "rates"]);
+    let allowed = words(&["stare", "tears"]);
+
+    let choice = best_guess_by(&candidates, &allowed, Strategy::Frequency, |a, b| b.cmp(a));
+
+    assert_eq!(choice.unwrap(), "tears");
+  }
+
+  #[test]
+  fn it_should_only_score_letter_frequencies_within_the_alphabet() {
+    let candidates = words(&["don't", "dolt"]);
+
+    assert_eq!(letter_frequency_score("don't", &candidates, &english_alphabet()), 7.0);
+    assert_eq!(letter_frequency_score("don't", &candidates, &['d', 'o']), 4.0);
+
+    let mut wordle = Wordle::new(words(&["αβγ", "αγδ", "βδε"]));
+    wordle.alphabet = vec!['α', 'β', 'γ', 'δ', 'ε'];
+    assert_eq!(wordle.best_guess(Strategy::Frequency).unwrap(), "αβγ");
+    assert_eq!(score(Strategy::Frequency, "αβγ", &wordle.dictionary), 0.0);
+  }
+
+  #[test]
+  fn it_should_count_every_occurrence_of_a_bigram() {
+    let candidates = words(&["those", "thick", "bathe", "salty"]);
+
+    let frequencies = bigram_frequencies(&candidates);
+
+    assert_eq!(frequencies[&('t', 'h')], 3);
+    assert_eq!(frequencies[&('s', 'a')], 1);
+    assert!(!frequencies.contains_key(&('h', 't')));
+    assert_eq!(bigram_score("thing", &candidates), 4.0);
+
+    let allowed = words(&["salty", "those"]);
+    assert_eq!(best_guess(&candidates, &allowed, Strategy::Bigram).unwrap(), "those");
+    assert_eq!(
+      score_all(Strategy::Bigram, &allowed, &candidates, &english_alphabet()),
+      vec![bigram_score("salty", &candidates), bigram_score("those", &candidates)]
+    );
+  }
+
+  #[test]
+  fn it_should_find_guesses_that_separate_every_answer() {
+    let answers = words(&["baker", "caker", "daker", "faker", "shirt"]);
+    let guesses = words(&["shirt", "baker", "bcdfz"]);
+
+    let set = minimal_separating_guesses(&answers, &guesses, 2).unwrap();
+
+    assert_eq!(set, vec!["bcdfz"]);
+    let feedback: HashSet<Vec<Vec<Correctness>>> = answers
+      .iter()
+      .map(|answer| set.iter().map(|guess| check_guess(guess, answer)).collect())
+      .collect();
+    assert_eq!(feedback.len(), answers.len());
+
+    assert!(minimal_separating_guesses(&answers, &words(&["shirt", "baker"]), 5).is_none());
+    assert_eq!(minimal_separating_guesses(&answers[..1], &[], 0), Some(vec![]));
+  }
+
+  #[test]
+  fn it_should_pick_the_word_testing_the_most_letters() {
+    let letters = ['b', 'c', 'l', 'm', 'p'];
+    let allowed = words(&["salty", "clamp", "plumb", "shirt"]);
+
+    let choice = best_guess_testing_letters(&letters, &allowed);
+
+    assert_eq!(choice, "clamp");
+    assert!(letters.iter().filter(|c| choice.contains(**c)).count() >= 3);
+  }
+
+  #[test]
+  fn it_should_pick_the_word_introducing_the_most_new_letters() {
+    let allowed = words(&["stare", "tears", "lynch", "geese"]);
+    let tested: HashSet<char> = "stare".chars().collect();
+
+    let choice = choose_max_new_letters(&allowed, &tested);
+
+    assert_eq!(choice, "lynch");
+
+    let mut wordle = Wordle::new(allowed);
+    wordle
+      .add_guess(Guess {
+        guess: String::from("stare"),
+        result: check_guess("stare", "geese"),
+      })
+      .unwrap();
+    assert_eq!(wordle.tested_letters(), tested);
+    assert_eq!(wordle.max_new_letters_guess().unwrap(), "lynch");
+  }
+
+  #[test]
+  fn it_should_pick_the_word_splitting_candidates_most_finely() {
+    let candidates = words(&["baker", "caker", "daker", "faker"]);
+    let allowed = words(&["baker", "bcdfz", "bcdzz"]);
+
+    assert_eq!(best_discriminator(&candidates, &allowed).unwrap(), "bcdfz");
+    assert!(best_discriminator(&candidates, &[]).is_none());
+  }
+
+  #[test]
+  fn it_should_score_entropy_the_same_with_letters_split_up_front() {
+    let list = words(&["baker", "caker", "daker", "faker", "shirt", "bcdfz", "geese"]);
+
+    let precomputed = entropy_all(&list, &list);
+
+    assert_eq!(precomputed.len(), list.len());
+    for (word, score) in list.iter().zip(precomputed) {
+      assert!((score - entropy(word, &list)).abs() < SCORE_EPSILON);
+    }
+  }
+
+  #[cfg(feature = "parallel")]
+  #[test]
+  fn it_should_score_and_pick_the_same_opener_as_a_serial_pass() {
+    let letters = ['b', 'c', 'd', 'f', 'h', 'l', 'm', 'p', 'r', 's', 't', 'w'];
+    let list: Vec<String> = letters
+      .iter()
+      .flat_map(|a| letters.iter().map(move |b| format!("{}a{}er", a, b)))
+      .collect();
+
+    let serial: Vec<f64> = list.iter().map(|w| score(Strategy::Entropy, w, &list)).collect();
+    let top = serial.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let expected = &list[serial.iter().position(|s| top - s < SCORE_EPSILON).unwrap()];
+
+    // Partition sums run in hash order, so scores only agree to within rounding
+    let parallel = score_all(Strategy::Entropy, &list, &list, &english_alphabet());
+    assert_eq!(parallel.len(), serial.len());
+    assert!(parallel.iter().zip(&serial).all(|(p, s)| (p - s).abs() < SCORE_EPSILON));
+    assert_eq!(best_guess(&list, &list, Strategy::Entropy).unwrap(), expected);
+  }
+
+  #[test]
+  fn it_should_return_none_when_nothing_is_allowed() {
+    let candidates = words(&["stare"]);
+
+    assert!(best_guess(&candidates, &[], Strategy::Minimax).is_none());
+  }
+
+  #[test]
+  fn it_should_rank_only_the_given_shortlist() {
+    let wordle = Wordle::new(words(&["baker", "caker", "daker", "faker", "bcdfz"]));
+    let shortlist = words(&["baker", "bcdzz"]);
+
+    assert_eq!(wordle.best_among(&shortlist, Strategy::Entropy).unwrap(), "bcdzz");
+    assert!(wordle.best_among(&[], Strategy::Entropy).is_none());
+  }
+
+  #[test]
+  fn it_should_skip_a_blocklisted_top_guess_for_the_next_best() {
+    let mut wordle = Wordle::new(words(&["baker", "caker", "daker", "faker"]));
+    assert_eq!(wordle.best_guess(Strategy::Entropy).unwrap(), "baker");
+
+    wordle.blocklist.insert(String::from("baker"));
+
+    assert_eq!(wordle.best_guess(Strategy::Entropy).unwrap(), "caker");
+    assert!(wordle.dictionary.contains(&String::from("baker")));
+  }
+
+  #[test]
+  fn it_should_only_pick_a_blocklisted_candidate_at_random_when_nothing_else_is_left() {
+    let mut wordle = Wordle::new(words(&["baker", "caker"]));
+    wordle.blocklist.insert(String::from("baker"));
+
+    for _ in 0..20 {
+      assert_eq!(RandomStrategy.choose(&wordle).unwrap(), "caker");
+    }
+
+    wordle.blocklist.insert(String::from("caker"));
+    assert!(RandomStrategy.choose(&wordle).is_some());
+  }
+}
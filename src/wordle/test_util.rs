@@ -0,0 +1,6 @@
+// Helpers shared by the unit tests
+
+pub(crate) fn words(list: &[&str]) -> Vec<String> {
+  list.iter().map(|w| w.to_string()).collect()
+}
+
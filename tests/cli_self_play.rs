@@ -0,0 +1,75 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn fixture(name: &str) -> PathBuf {
+  Path::new(env!("CARGO_MANIFEST_DIR")).join("src/wordle/fixtures").join(name)
+}
+
+// The same pairs the library's regression test solves, played here through the binary
+fn tricky_pairs() -> Vec<(String, String)> {
+  std::fs::read_to_string(fixture("tricky_pairs.txt"))
+    .unwrap()
+    .lines()
+    .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+    .map(|line| {
+      let words: Vec<&str> = line.split_whitespace().collect();
+      assert_eq!(words.len(), 2, "Malformed fixture line {:?}", line);
+      (words[0].to_string(), words[1].to_string())
+    })
+    .collect()
+}
+
+#[test]
+fn it_should_self_play_every_tricky_pair_within_six_guesses() {
+  let words = fixture("tricky_words.txt");
+  let pairs = tricky_pairs();
+  assert!(!pairs.is_empty());
+
+  for (opener, answer) in pairs {
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle_solver"))
+      .arg(&words)
+      .arg(&opener)
+      .arg(&answer)
+      .output()
+      .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{} -> {} failed:\n{}", opener, answer, stdout);
+    assert!(
+      stdout.contains(&format!("The correct word is {:?}.", answer)),
+      "{} didn't find {}:\n{}",
+      opener,
+      answer,
+      stdout
+    );
+
+    let guesses: usize = stdout
+      .lines()
+      .find_map(|line| line.strip_prefix("It took "))
+      .and_then(|rest| rest.split_whitespace().next())
+      .and_then(|count| count.parse().ok())
+      .unwrap_or_else(|| panic!("No guess count in:\n{}", stdout));
+    assert!(guesses <= 6, "{} took {} guesses to find {}", opener, guesses, answer);
+  }
+}
+
+#[test]
+fn it_should_refuse_max_list_without_a_reveal_threshold() {
+  let words = fixture("tricky_words.txt");
+  let run = |extra: &[&str]| {
+    Command::new(env!("CARGO_BIN_EXE_wordle_solver"))
+      .args(extra)
+      .arg(&words)
+      .args(["geese", "eerie"])
+      .output()
+      .unwrap()
+  };
+
+  let refused = run(&["--max-list", "1"]);
+  assert!(!refused.status.success());
+  assert!(String::from_utf8_lossy(&refused.stdout).contains("--reveal-threshold"));
+
+  let listed = run(&["--max-list", "1", "--reveal-threshold", "31"]);
+  assert!(listed.status.success());
+  assert!(String::from_utf8_lossy(&listed.stdout).contains("... and 1 more"));
+}